@@ -0,0 +1,238 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::{emit_error, SpanRange};
+use syn::{Expr, ExprLit, Lit, LitStr};
+
+use crate::prefixed_map::literal_entries;
+use crate::suggestion::{did_you_mean, unknown_name_warning};
+use crate::yew_macro::props::{ElementProps, Prop};
+
+/// Value type of an ARIA attribute.
+enum AriaType {
+    TrueFalse,
+    TrueFalseUndefined,
+    Tristate,
+    Integer,
+    Number,
+    Token(&'static [&'static str]),
+    TokenList(&'static [&'static str]),
+    /// String, ID reference or ID reference list, which are not checked.
+    Other,
+}
+
+// From: https://www.w3.org/TR/wai-aria-1.2/#state_prop_def
+static ARIA_ATTRIBUTES: &[(&str, AriaType)] = &[
+    ("aria-activedescendant", AriaType::Other),
+    ("aria-atomic", AriaType::TrueFalse),
+    (
+        "aria-autocomplete",
+        AriaType::Token(&["inline", "list", "both", "none"]),
+    ),
+    ("aria-busy", AriaType::TrueFalse),
+    ("aria-checked", AriaType::Tristate),
+    ("aria-colcount", AriaType::Integer),
+    ("aria-colindex", AriaType::Integer),
+    ("aria-colspan", AriaType::Integer),
+    ("aria-controls", AriaType::Other),
+    (
+        "aria-current",
+        AriaType::Token(&["page", "step", "location", "date", "time", "true", "false"]),
+    ),
+    ("aria-describedby", AriaType::Other),
+    ("aria-details", AriaType::Other),
+    ("aria-disabled", AriaType::TrueFalse),
+    (
+        "aria-dropeffect",
+        AriaType::TokenList(&["copy", "execute", "link", "move", "none", "popup"]),
+    ),
+    ("aria-errormessage", AriaType::Other),
+    ("aria-expanded", AriaType::TrueFalseUndefined),
+    ("aria-flowto", AriaType::Other),
+    ("aria-grabbed", AriaType::TrueFalseUndefined),
+    (
+        "aria-haspopup",
+        AriaType::Token(&["false", "true", "menu", "listbox", "tree", "grid", "dialog"]),
+    ),
+    ("aria-hidden", AriaType::TrueFalseUndefined),
+    (
+        "aria-invalid",
+        AriaType::Token(&["grammar", "false", "spelling", "true"]),
+    ),
+    ("aria-keyshortcuts", AriaType::Other),
+    ("aria-label", AriaType::Other),
+    ("aria-labelledby", AriaType::Other),
+    ("aria-level", AriaType::Integer),
+    (
+        "aria-live",
+        AriaType::Token(&["assertive", "off", "polite"]),
+    ),
+    ("aria-modal", AriaType::TrueFalse),
+    ("aria-multiline", AriaType::TrueFalse),
+    ("aria-multiselectable", AriaType::TrueFalse),
+    (
+        "aria-orientation",
+        AriaType::Token(&["horizontal", "undefined", "vertical"]),
+    ),
+    ("aria-owns", AriaType::Other),
+    ("aria-placeholder", AriaType::Other),
+    ("aria-posinset", AriaType::Integer),
+    ("aria-pressed", AriaType::Tristate),
+    ("aria-readonly", AriaType::TrueFalse),
+    (
+        "aria-relevant",
+        AriaType::TokenList(&["additions", "all", "removals", "text"]),
+    ),
+    ("aria-required", AriaType::TrueFalse),
+    ("aria-roledescription", AriaType::Other),
+    ("aria-rowcount", AriaType::Integer),
+    ("aria-rowindex", AriaType::Integer),
+    ("aria-rowspan", AriaType::Integer),
+    ("aria-selected", AriaType::TrueFalseUndefined),
+    ("aria-setsize", AriaType::Integer),
+    (
+        "aria-sort",
+        AriaType::Token(&["ascending", "descending", "none", "other"]),
+    ),
+    ("aria-valuemax", AriaType::Number),
+    ("aria-valuemin", AriaType::Number),
+    ("aria-valuenow", AriaType::Number),
+    ("aria-valuetext", AriaType::Other),
+];
+
+/// `aria-*` attribute with the span of its name and its value.
+struct AriaAttribute {
+    name: String,
+    span: SpanRange,
+    value: Expr,
+}
+
+/// `aria-*` attributes, including literal keys and values of the `aria={map}` shorthand.
+fn aria_attributes(props: &ElementProps) -> Vec<AriaAttribute> {
+    let mut aria_attributes = vec![];
+
+    for Prop {
+        label,
+        value,
+        directive,
+        ..
+    } in &props.attributes
+    {
+        let name = label.to_string();
+        if directive.is_none() && name.starts_with("aria-") {
+            aria_attributes.push(AriaAttribute {
+                name,
+                span: SpanRange::from_tokens(label),
+                value: value.clone(),
+            });
+        }
+    }
+
+    for prop in &props.prefixed_maps {
+        if prop.label.name == "aria" {
+            for (key, value) in literal_entries(&prop.value) {
+                aria_attributes.push(AriaAttribute {
+                    name: format!("aria-{}", key.value()),
+                    span: SpanRange::from_tokens(&key),
+                    value,
+                });
+            }
+        }
+    }
+
+    aria_attributes
+}
+
+fn aria_type(name: &str) -> Option<&'static AriaType> {
+    ARIA_ATTRIBUTES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, aria_type)| aria_type)
+}
+
+/// Emit errors for invalid literal values of known `aria-*` attributes.
+pub fn validate(props: &ElementProps) {
+    for AriaAttribute { name, value, .. } in aria_attributes(props) {
+        if let (
+            Some(aria_type),
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(value),
+                ..
+            }),
+        ) = (aria_type(&name), value)
+        {
+            validate_value(&name, aria_type, &value);
+        }
+    }
+}
+
+/// Warn about `aria-*` attributes which are not WAI-ARIA 1.2 attributes, e.g. a misspelled attribute like `aria-lable`.
+///
+/// Newer attributes like `aria-description` are unknown as well, so this is a warning instead of an error.
+pub fn unknown_attributes(props: &ElementProps) -> TokenStream {
+    aria_attributes(props)
+        .into_iter()
+        .filter(|AriaAttribute { name, .. }| aria_type(name).is_none())
+        .map(|AriaAttribute { name, span, .. }| {
+            unknown_name_warning(
+                span.collapse(),
+                "unknown_aria_attribute",
+                format!("unknown ARIA attribute `{name}`"),
+                &name,
+                ARIA_ATTRIBUTES.iter().map(|(known, _)| *known),
+            )
+        })
+        .collect()
+}
+
+fn validate_value(name: &str, aria_type: &AriaType, lit: &LitStr) {
+    let value = lit.value();
+
+    let tokens: &[&str] = match aria_type {
+        AriaType::TrueFalse => &["true", "false"],
+        AriaType::TrueFalseUndefined => &["true", "false", "undefined"],
+        AriaType::Tristate => &["true", "false", "mixed", "undefined"],
+        AriaType::Token(tokens) | AriaType::TokenList(tokens) => tokens,
+        AriaType::Integer => {
+            if value.trim().parse::<i64>().is_err() {
+                emit_error!(
+                    lit,
+                    "invalid value `{}` for `{}`, expected an integer",
+                    value,
+                    name
+                );
+            }
+            return;
+        }
+        AriaType::Number => {
+            if !value.trim().parse::<f64>().is_ok_and(f64::is_finite) {
+                emit_error!(
+                    lit,
+                    "invalid value `{}` for `{}`, expected a number",
+                    value,
+                    name
+                );
+            }
+            return;
+        }
+        AriaType::Other => return,
+    };
+
+    let values: Vec<&str> = match aria_type {
+        AriaType::TokenList(_) => value.split_ascii_whitespace().collect(),
+        _ => vec![value.trim()],
+    };
+
+    for value in values {
+        if !tokens.iter().any(|token| token.eq_ignore_ascii_case(value)) {
+            let expected = tokens
+                .iter()
+                .map(|token| format!("`{token}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            emit_error!(
+                lit, "invalid value `{}` for `{}`, expected one of {}", value, name, expected;
+                help =? did_you_mean(&value.to_ascii_lowercase(), tokens.iter().copied())
+            );
+        }
+    }
+}
@@ -1,24 +1,77 @@
+use std::collections::BTreeMap;
+
 use proc_macro2::{Span, TokenStream};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Expr, Lit, LitStr};
+use syn::token::Brace;
+use syn::{braced, Expr, Lit, LitStr, Token};
 
-use crate::yew_macro::props::{ClassesForm, ElementProps, Prop, PropDirective};
+use crate::yew_macro::html_tree::HtmlDashedName;
+use crate::yew_macro::props::{ClassesForm, Condition, ElementProps, Prop, PropDirective};
 use crate::yew_macro::stringify::{Stringify, Value};
+use crate::{aria, element, lint, prefixed_map};
 
-pub struct Attrs(ElementProps);
+pub struct Attrs {
+    props: ElementProps,
+    base: Option<Expr>,
+}
 
 impl Parse for Attrs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let attrs = input.parse().map(Self)?;
+        if !Self::peek_element(input) {
+            return Self::parse_attrs(input);
+        }
+
+        input.parse::<Token![for]>()?;
+        let element = input.parse::<HtmlDashedName>()?;
+        let content;
+        braced!(content in input);
+        let attrs = Self::parse_attrs(&content)?;
+        element::validate(&element, &attrs.props);
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the attributes of the element"));
+        }
+
+        Ok(attrs)
+    }
+}
+
+impl Attrs {
+    /// Whether the input starts with an element, i.e. `for button { ... }`.
+    fn peek_element(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<Token![for]>().is_ok()
+            && fork.parse::<HtmlDashedName>().is_ok()
+            && fork.peek(Brace)
+    }
+
+    fn parse_attrs(input: ParseStream) -> syn::Result<Self> {
+        let props = input.parse::<ElementProps>()?;
+        aria::validate(&props);
 
-        if attrs.0.special.key.is_some() {
+        let base = if input.peek(Token![..]) {
+            input.parse::<Token![..]>()?;
+            let base = input.parse::<Expr>()?;
+
+            if !input.is_empty() {
+                return Err(input.error("base expression must be the last item in `attrs!`"));
+            }
+
+            Some(base)
+        } else {
+            None
+        };
+
+        let attrs = Self { props, base };
+
+        if attrs.props.special.key.is_some() {
             Err(syn::Error::new(
                 input.span(),
                 "special prop \"key\" is not allowed as attribute",
             ))
-        } else if attrs.0.special.node_ref.is_some() {
+        } else if attrs.props.special.node_ref.is_some() {
             Err(syn::Error::new(
                 input.span(),
                 "special prop \"node_ref\" is not allowed as attribute",
@@ -33,53 +86,81 @@ impl Parse for Attrs {
 impl ToTokens for Attrs {
     #[allow(clippy::cognitive_complexity)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let props = &self.0;
+        let props = &self.props;
 
         let ElementProps {
             classes,
+            conditional_classes,
             attributes,
             booleans,
             listeners,
+            prefixed_maps,
             ..
         } = &props;
 
+        let conditions = conditions(
+            attributes
+                .iter()
+                .chain(booleans)
+                .chain(listeners)
+                .chain(conditional_classes)
+                .chain(prefixed_maps),
+        );
+
         let attributes = {
             let normal_attrs = attributes.iter().map(
                 |Prop {
                      label,
                      value,
                      directive,
-                     ..
+                     conditions,
                  }| {
                     (
                         label.to_lit_str(),
                         value.optimize_literals_tagged(),
                         *directive,
+                        condition(conditions),
                     )
                 },
             );
-            let boolean_attrs = booleans.iter().filter_map(
-                |Prop {
-                     label,
-                     value,
-                     directive,
-                     ..
-                 }| {
+            let boolean_attrs = booleans.iter().enumerate().filter_map(
+                |(
+                    index,
+                    Prop {
+                        label,
+                        value,
+                        directive,
+                        conditions,
+                    },
+                )| {
                     let key = label.to_lit_str();
                     Some((
                         key.clone(),
                         match value {
                             Expr::Lit(e) => match &e.lit {
-                                Lit::Bool(b) => Value::Static(if b.value {
-                                    quote! { #key }
-                                } else {
-                                    return None;
+                                Lit::Bool(b) if b.value => Value::Static(quote! { #key }),
+                                // A conditional `false` still removes the attribute set by an earlier prop.
+                                Lit::Bool(_) if !conditions.is_empty() => Value::Dynamic(quote! {
+                                    ::std::option::Option::None::<::yew::virtual_dom::AttrValue>
                                 }),
+                                Lit::Bool(_) => return None,
                                 _ => Value::Dynamic(quote_spanned! {value.span()=> {
                                     ::yew::utils::__ensure_type::<::std::primitive::bool>(#value);
                                     #key
                                 }}),
                             },
+                            _ if is_checked(label) => {
+                                let checked = checked_ident(index);
+                                Value::Dynamic(quote! {
+                                    if #checked == ::std::option::Option::Some(true) {
+                                        ::std::option::Option::Some(
+                                            ::yew::virtual_dom::AttrValue::Static(#key)
+                                        )
+                                    } else {
+                                        ::std::option::Option::None
+                                    }
+                                })
+                            }
                             expr => Value::Dynamic(
                                 quote_spanned! {expr.span().resolved_at(Span::call_site())=>
                                     if #expr {
@@ -93,6 +174,7 @@ impl ToTokens for Attrs {
                             ),
                         },
                         *directive,
+                        condition(conditions),
                     ))
                 },
             );
@@ -125,6 +207,7 @@ impl ToTokens for Attrs {
                             }
                         }),
                         None,
+                        None,
                     ))
                 }
                 ClassesForm::Single(classes) => {
@@ -137,6 +220,7 @@ impl ToTokens for Attrs {
                                     LitStr::new("class", lit.span()),
                                     Value::Static(quote! { #lit }),
                                     None,
+                                    None,
                                 ))
                             }
                         }
@@ -147,49 +231,167 @@ impl ToTokens for Attrs {
                                     ::std::convert::Into::<::yew::html::Classes>::into(#classes)
                                 }),
                                 None,
+                                None,
                             ))
                         }
                     }
                 }
             });
+            let class_attr = class_attr.filter(|_| conditional_classes.is_empty());
+
+            // Conditional classes are combined with the other classes, which are always included.
+            let conditional_class_attr = (!conditional_classes.is_empty()).then(|| {
+                let classes = classes.as_ref().map(|classes| match classes {
+                    ClassesForm::Tuple(classes) => {
+                        let classes = classes.elems.iter();
+                        quote! { #(__yew_classes.push(#classes);)* }
+                    }
+                    ClassesForm::Single(classes) => quote! { __yew_classes.push(#classes); },
+                });
+                let conditional_classes = conditional_classes.iter().map(
+                    |Prop {
+                         value, conditions, ..
+                     }| {
+                        let condition = condition(conditions);
+                        quote! {
+                            if #condition {
+                                __yew_classes.push(#value);
+                            }
+                        }
+                    },
+                );
+
+                (
+                    LitStr::new("class", Span::call_site()),
+                    Value::Dynamic(quote! {
+                        {
+                            let mut __yew_classes = ::yew::html::Classes::new();
+                            #classes
+                            #(#conditional_classes)*
+                            __yew_classes
+                        }
+                    }),
+                    None,
+                    None,
+                )
+            });
 
             fn apply_as(directive: Option<&PropDirective>) -> TokenStream {
                 match directive {
                     Some(PropDirective::ApplyAsProperty(token)) => {
                         quote_spanned!(token.span()=> ::yew::virtual_dom::ApplyAttributeAs::Property)
                     }
-                    None => quote!(::yew::virtual_dom::ApplyAttributeAs::Attribute),
+                    Some(PropDirective::Listener { .. }) | None => {
+                        quote!(::yew::virtual_dom::ApplyAttributeAs::Attribute)
+                    }
                 }
             }
 
             let attrs = normal_attrs
                 .chain(boolean_attrs)
                 .chain(class_attr)
-                .collect::<Vec<(LitStr, Value, Option<PropDirective>)>>();
+                .chain(conditional_class_attr)
+                .collect::<Vec<(LitStr, Value, Option<PropDirective>, Option<TokenStream>)>>();
 
-            let values = attrs.iter().map(|(key, value, directive)| {
+            let n = attrs.len();
+            let inserts = attrs.iter().map(|(key, value, directive, condition)| {
                 let value = wrap_attr_value(value);
                 let apply_as = apply_as(directive.as_ref());
 
-                quote! { (::yew::AttrValue::from(#key), (#value.unwrap(), #apply_as)) }
+                let insert = quote! {
+                    if let ::std::option::Option::Some(__yew_value) = #value {
+                        __yew_map.insert(::yew::AttrValue::from(#key), (__yew_value, #apply_as));
+                    }
+                };
+
+                // Values of omitted conditional attributes are not evaluated. A conditional attribute overrides an earlier
+                // prop with the same name, so a `None` value removes the attribute.
+                match condition {
+                    Some(condition) => quote! {
+                        if #condition {
+                            match #value {
+                                ::std::option::Option::Some(__yew_value) => {
+                                    __yew_map.insert(::yew::AttrValue::from(#key), (__yew_value, #apply_as));
+                                }
+                                ::std::option::Option::None => {
+                                    __yew_map.shift_remove(#key);
+                                }
+                            }
+                        }
+                    },
+                    None => insert,
+                }
+            });
+
+            let prefixed_map_inserts = prefixed_maps.iter().map(|prop| {
+                let insert = prefixed_map::insert_tokens(prop);
+
+                match condition(&prop.conditions) {
+                    Some(condition) => quote! {
+                        if #condition {
+                            #insert
+                        }
+                    },
+                    None => insert,
+                }
             });
 
             quote! {
-                ::yew::virtual_dom::Attributes::IndexMap([
-                    #(#values),*
-                ].into())
+                {
+                    let mut __yew_attributes = ::yew::virtual_dom::Attributes::IndexMap(
+                        ::std::default::Default::default()
+                    );
+                    let __yew_map = __yew_attributes.get_mut_index_map();
+                    __yew_map.reserve(#n);
+                    #(#inserts)*
+                    #(#prefixed_map_inserts)*
+                    __yew_attributes
+                }
             }
         };
 
         let listeners = if listeners.is_empty() {
             quote! { ::yew::virtual_dom::listeners::Listeners::None }
         } else {
-            let listeners_it = listeners.iter().map(|Prop { label, value, .. }| {
-                let name = &label.name;
-                quote! {
-                    ::yew::html::#name::Wrapper::__macro_new(#value)
-                }
-            });
+            let listeners_it = listeners.iter().map(
+                |Prop {
+                     label,
+                     value,
+                     directive,
+                     conditions,
+                 }| {
+                    let listener = match directive {
+                        Some(PropDirective::Listener { passive }) => {
+                            let event = label.to_lit_str();
+                            let constructor = if *passive {
+                                quote! { on_passive }
+                            } else {
+                                quote! { on }
+                            };
+                            quote! {
+                                ::yew_attrs::listeners::#constructor(#event, #value)
+                            }
+                        }
+                        _ => {
+                            let name = &label.name;
+                            quote! {
+                                ::yew::html::#name::Wrapper::__macro_new(#value)
+                            }
+                        }
+                    };
+
+                    match condition(conditions) {
+                        Some(condition) => quote! {
+                            if #condition {
+                                #listener
+                            } else {
+                                ::std::option::Option::None
+                            }
+                        },
+                        None => listener,
+                    }
+                },
+            );
 
             quote! {
                 ::yew::virtual_dom::listeners::Listeners::Pending(
@@ -198,15 +400,204 @@ impl ToTokens for Attrs {
             }
         };
 
-        tokens.extend(quote! {
-            ::yew_attrs::Attrs::new(
-                #attributes,
-                #listeners,
-            )
+        // `checked` is also kept as tri-state, so `checked=false` can explicitly uncheck inputs. A dynamic value is
+        // evaluated once into a variable, which is used for both the attribute and the checked state.
+        let checked_props = booleans
+            .iter()
+            .enumerate()
+            .filter(|(_, prop)| is_checked(&prop.label));
+        let checked_values = checked_props.clone().filter_map(
+            |(
+                index,
+                Prop {
+                    value, conditions, ..
+                },
+            )| {
+                if let Expr::Lit(_) = value {
+                    return None;
+                }
+
+                let checked = checked_ident(index);
+                let value = quote_spanned! {value.span()=>
+                    ::std::option::Option::Some::<::std::primitive::bool>(#value)
+                };
+                Some(match condition(conditions) {
+                    Some(condition) => quote! {
+                        let #checked = if #condition {
+                            #value
+                        } else {
+                            ::std::option::Option::None
+                        };
+                    },
+                    None => quote! {
+                        let #checked = #value;
+                    },
+                })
+            },
+        );
+        let checked = checked_props.filter_map(
+            |(
+                index,
+                Prop {
+                    value, conditions, ..
+                },
+            )| {
+                match value {
+                    Expr::Lit(e) => {
+                        if !matches!(e.lit, Lit::Bool(_)) {
+                            return None;
+                        }
+
+                        let with_checked =
+                            quote_spanned! {value.span()=> __yew_attrs.with_checked(#value)};
+                        Some(match condition(conditions) {
+                            Some(condition) => quote! {
+                                let __yew_attrs = if #condition {
+                                    #with_checked
+                                } else {
+                                    __yew_attrs
+                                };
+                            },
+                            None => quote! {
+                                let __yew_attrs = #with_checked;
+                            },
+                        })
+                    }
+                    _ => {
+                        let checked = checked_ident(index);
+                        Some(quote! {
+                            let __yew_attrs = match #checked {
+                                ::std::option::Option::Some(__yew_checked) => {
+                                    __yew_attrs.with_checked(__yew_checked)
+                                }
+                                ::std::option::Option::None => __yew_attrs,
+                            };
+                        })
+                    }
+                }
+            },
+        );
+
+        let lints = lint::unknown_attributes(props);
+        let aria_lints = aria::unknown_attributes(props);
+
+        let attrs = quote! {
+            {
+                #lints
+                #aria_lints
+                #conditions
+                #(#checked_values)*
+                let __yew_attrs = ::yew_attrs::Attrs::new(
+                    #attributes,
+                    #listeners,
+                );
+                #(#checked)*
+                __yew_attrs
+            }
+        };
+
+        tokens.extend(match &self.base {
+            Some(base) => quote! {
+                ::yew_attrs::Attrs::merge_with(#base, #attrs, &::std::default::Default::default())
+            },
+            None => attrs,
         });
     }
 }
 
+/// Whether a boolean attribute is `checked`, which also sets the checked state.
+fn is_checked(label: &HtmlDashedName) -> bool {
+    label.to_string() == "checked"
+}
+
+fn checked_ident(index: usize) -> syn::Ident {
+    format_ident!("__yew_checked_{}", index)
+}
+
+fn condition_ident(index: usize) -> syn::Ident {
+    format_ident!("__yew_condition_{}", index)
+}
+
+/// Conditions of a conditional group, by index, with the conditions of the enclosing branch.
+type ConditionTree<'a> = BTreeMap<usize, (&'a Expr, Vec<(usize, bool)>)>;
+
+/// Evaluate the conditions of the conditional groups once, before any values.
+///
+/// The conditions are evaluated in nested `if` blocks mirroring the conditional groups, so a condition is only
+/// evaluated if the branch containing it is taken. Each result is stored in a variable, which is `false` if the
+/// condition is not evaluated.
+fn conditions<'a>(props: impl Iterator<Item = &'a Prop>) -> TokenStream {
+    let mut tree = ConditionTree::new();
+    for prop in props {
+        for (position, condition) in prop.conditions.iter().enumerate() {
+            tree.entry(condition.index).or_insert_with(|| {
+                let branch = prop.conditions[..position]
+                    .iter()
+                    .map(|condition| (condition.index, condition.negated))
+                    .collect();
+                (condition.expr.as_ref(), branch)
+            });
+        }
+    }
+
+    let declarations = tree.keys().map(|index| {
+        let ident = condition_ident(*index);
+        quote! {
+            let mut #ident: ::std::primitive::bool = false;
+        }
+    });
+    let evaluations = nested_conditions(&tree, &[]);
+
+    quote! {
+        #(#declarations)*
+        #evaluations
+    }
+}
+
+/// Evaluate the conditions in the given branch, followed by the conditions in their own branches.
+fn nested_conditions(tree: &ConditionTree, branch: &[(usize, bool)]) -> TokenStream {
+    tree.iter()
+        .filter(|(_, (_, enclosing))| enclosing == branch)
+        .map(|(index, (expr, _))| {
+            let ident = condition_ident(*index);
+            let nested = |negated: bool| {
+                let mut branch = branch.to_vec();
+                branch.push((*index, negated));
+                nested_conditions(tree, &branch)
+            };
+            let then_branch = nested(false);
+            let else_branch = nested(true);
+            let else_branch = (!else_branch.is_empty()).then(|| quote! { else { #else_branch } });
+
+            quote! {
+                if #expr {
+                    #ident = true;
+                    #then_branch
+                }
+                #else_branch
+            }
+        })
+        .collect()
+}
+
+/// Combined conditions of the enclosing conditional groups of a prop, if any.
+fn condition(conditions: &[Condition]) -> Option<TokenStream> {
+    if conditions.is_empty() {
+        return None;
+    }
+
+    let conditions = conditions.iter().map(|condition| {
+        let ident = condition_ident(condition.index);
+        if condition.negated {
+            quote! { !#ident }
+        } else {
+            quote! { #ident }
+        }
+    });
+
+    Some(quote! { #(#conditions)&&* })
+}
+
 fn wrap_attr_value<T: ToTokens>(value: T) -> TokenStream {
     quote_spanned! {value.span()=>
         ::yew::html::IntoPropValue::<
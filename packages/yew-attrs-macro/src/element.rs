@@ -0,0 +1,448 @@
+use proc_macro_error::{emit_error, SpanRange};
+
+use crate::suggestion::did_you_mean;
+use crate::yew_macro::html_tree::HtmlDashedName;
+use crate::yew_macro::props::{ElementProps, Prop};
+
+// From: https://html.spec.whatwg.org/#global-attributes
+// Including `class`, `id` and `role`, which are also allowed on all elements.
+static GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autocorrect",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+    "writingsuggestions",
+];
+
+// From: https://html.spec.whatwg.org/#elements-3
+// Content attributes of each element, excluding global attributes. `value` is included for `select` and `textarea`,
+// where `Attrs::new_vtag` and `Attrs::apply_vtag` apply it as value.
+static ELEMENTS: &[(&str, &[&str])] = &[
+    (
+        "a",
+        &[
+            "download",
+            "href",
+            "hreflang",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "target",
+            "type",
+        ],
+    ),
+    ("abbr", &[]),
+    ("address", &[]),
+    (
+        "area",
+        &[
+            "alt",
+            "coords",
+            "download",
+            "href",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "shape",
+            "target",
+        ],
+    ),
+    ("article", &[]),
+    ("aside", &[]),
+    (
+        "audio",
+        &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "loop",
+            "muted",
+            "preload",
+            "src",
+        ],
+    ),
+    ("b", &[]),
+    ("base", &["href", "target"]),
+    ("bdi", &[]),
+    ("bdo", &[]),
+    ("blockquote", &["cite"]),
+    ("body", &[]),
+    ("br", &[]),
+    (
+        "button",
+        &[
+            "command",
+            "commandfor",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "name",
+            "popovertarget",
+            "popovertargetaction",
+            "type",
+            "value",
+        ],
+    ),
+    ("canvas", &["height", "width"]),
+    ("caption", &[]),
+    ("cite", &[]),
+    ("code", &[]),
+    ("col", &["span"]),
+    ("colgroup", &["span"]),
+    ("data", &["value"]),
+    ("datalist", &[]),
+    ("dd", &[]),
+    ("del", &["cite", "datetime"]),
+    ("details", &["name", "open"]),
+    ("dfn", &[]),
+    ("dialog", &["closedby", "open"]),
+    ("div", &[]),
+    ("dl", &[]),
+    ("dt", &[]),
+    ("em", &[]),
+    ("embed", &["height", "src", "type", "width"]),
+    ("fieldset", &["disabled", "form", "name"]),
+    ("figcaption", &[]),
+    ("figure", &[]),
+    ("footer", &[]),
+    (
+        "form",
+        &[
+            "accept-charset",
+            "action",
+            "autocomplete",
+            "enctype",
+            "method",
+            "name",
+            "novalidate",
+            "rel",
+            "target",
+        ],
+    ),
+    ("h1", &[]),
+    ("h2", &[]),
+    ("h3", &[]),
+    ("h4", &[]),
+    ("h5", &[]),
+    ("h6", &[]),
+    ("head", &[]),
+    ("header", &[]),
+    ("hgroup", &[]),
+    ("hr", &[]),
+    ("html", &[]),
+    ("i", &[]),
+    (
+        "iframe",
+        &[
+            "allow",
+            "allowfullscreen",
+            "height",
+            "loading",
+            "name",
+            "referrerpolicy",
+            "sandbox",
+            "src",
+            "srcdoc",
+            "width",
+        ],
+    ),
+    (
+        "img",
+        &[
+            "alt",
+            "crossorigin",
+            "decoding",
+            "fetchpriority",
+            "height",
+            "ismap",
+            "loading",
+            "referrerpolicy",
+            "sizes",
+            "src",
+            "srcset",
+            "usemap",
+            "width",
+        ],
+    ),
+    (
+        "input",
+        &[
+            "accept",
+            "alpha",
+            "alt",
+            "autocomplete",
+            "checked",
+            "colorspace",
+            "dirname",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "height",
+            "list",
+            "max",
+            "maxlength",
+            "min",
+            "minlength",
+            "multiple",
+            "name",
+            "pattern",
+            "placeholder",
+            "popovertarget",
+            "popovertargetaction",
+            "readonly",
+            "required",
+            "size",
+            "src",
+            "step",
+            "type",
+            "value",
+            "webkitdirectory",
+            "width",
+        ],
+    ),
+    ("ins", &["cite", "datetime"]),
+    ("kbd", &[]),
+    ("label", &["for"]),
+    ("legend", &[]),
+    ("li", &["value"]),
+    (
+        "link",
+        &[
+            "as",
+            "blocking",
+            "color",
+            "crossorigin",
+            "disabled",
+            "fetchpriority",
+            "href",
+            "hreflang",
+            "imagesizes",
+            "imagesrcset",
+            "integrity",
+            "media",
+            "referrerpolicy",
+            "rel",
+            "sizes",
+            "type",
+        ],
+    ),
+    ("main", &[]),
+    ("map", &["name"]),
+    ("mark", &[]),
+    ("menu", &[]),
+    (
+        "meta",
+        &["charset", "content", "http-equiv", "media", "name"],
+    ),
+    ("meter", &["high", "low", "max", "min", "optimum", "value"]),
+    ("nav", &[]),
+    ("noscript", &[]),
+    (
+        "object",
+        &["data", "form", "height", "name", "type", "width"],
+    ),
+    ("ol", &["reversed", "start", "type"]),
+    ("optgroup", &["disabled", "label"]),
+    ("option", &["disabled", "label", "selected", "value"]),
+    ("output", &["for", "form", "name"]),
+    ("p", &[]),
+    ("picture", &[]),
+    ("pre", &[]),
+    ("progress", &["max", "value"]),
+    ("q", &["cite"]),
+    ("rp", &[]),
+    ("rt", &[]),
+    ("ruby", &[]),
+    ("s", &[]),
+    ("samp", &[]),
+    (
+        "script",
+        &[
+            "async",
+            "blocking",
+            "crossorigin",
+            "defer",
+            "fetchpriority",
+            "integrity",
+            "nomodule",
+            "referrerpolicy",
+            "src",
+            "type",
+        ],
+    ),
+    ("search", &[]),
+    ("section", &[]),
+    (
+        "select",
+        &[
+            "autocomplete",
+            "disabled",
+            "form",
+            "multiple",
+            "name",
+            "required",
+            "size",
+            "value",
+        ],
+    ),
+    ("slot", &["name"]),
+    ("small", &[]),
+    (
+        "source",
+        &["height", "media", "sizes", "src", "srcset", "type", "width"],
+    ),
+    ("span", &[]),
+    ("strong", &[]),
+    ("style", &["blocking", "media"]),
+    ("sub", &[]),
+    ("summary", &[]),
+    ("sup", &[]),
+    ("table", &[]),
+    ("tbody", &[]),
+    ("td", &["colspan", "headers", "rowspan"]),
+    (
+        "template",
+        &[
+            "shadowrootclonable",
+            "shadowrootdelegatesfocus",
+            "shadowrootmode",
+            "shadowrootserializable",
+        ],
+    ),
+    (
+        "textarea",
+        &[
+            "autocomplete",
+            "cols",
+            "dirname",
+            "disabled",
+            "form",
+            "maxlength",
+            "minlength",
+            "name",
+            "placeholder",
+            "readonly",
+            "required",
+            "rows",
+            "value",
+            "wrap",
+        ],
+    ),
+    ("tfoot", &[]),
+    ("th", &["abbr", "colspan", "headers", "rowspan", "scope"]),
+    ("thead", &[]),
+    ("time", &["datetime"]),
+    ("title", &[]),
+    ("tr", &[]),
+    ("track", &["default", "kind", "label", "src", "srclang"]),
+    ("u", &[]),
+    ("ul", &[]),
+    ("var", &[]),
+    (
+        "video",
+        &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "height",
+            "loop",
+            "muted",
+            "playsinline",
+            "poster",
+            "preload",
+            "src",
+            "width",
+        ],
+    ),
+    ("wbr", &[]),
+];
+
+/// Global attributes and content attributes of all HTML elements, which may contain duplicates.
+pub fn attributes() -> impl Iterator<Item = &'static str> {
+    GLOBAL_ATTRIBUTES
+        .iter()
+        .chain(
+            ELEMENTS
+                .iter()
+                .flat_map(|(_, attributes)| attributes.iter()),
+        )
+        .copied()
+}
+
+/// Emit errors for attributes and boolean attributes which are not content attributes of the given HTML element.
+///
+/// Global attributes, `data-*` and `aria-*` attributes, listeners and attributes applied as property are allowed on
+/// all elements.
+pub fn validate(element: &HtmlDashedName, props: &ElementProps) {
+    let name = element.to_string();
+    if name.contains('-') {
+        emit_error!(
+            SpanRange::from_tokens(element),
+            "custom element `{}` has no known attributes, use `attrs!` without `for {}`",
+            name,
+            name
+        );
+        return;
+    }
+
+    let Some((_, attributes)) = ELEMENTS.iter().find(|(known, _)| *known == name) else {
+        emit_error!(
+            SpanRange::from_tokens(element), "unknown HTML element `{}`", name;
+            help =? did_you_mean(&name, ELEMENTS.iter().map(|(known, _)| *known))
+        );
+        return;
+    };
+
+    for Prop {
+        label, directive, ..
+    } in props.attributes.iter().chain(&props.booleans)
+    {
+        let attribute = label.to_string().to_ascii_lowercase();
+        if directive.is_some()
+            || attribute.starts_with("data-")
+            || attribute.starts_with("aria-")
+            || GLOBAL_ATTRIBUTES.contains(&attribute.as_str())
+            || attributes.contains(&attribute.as_str())
+        {
+            continue;
+        }
+
+        emit_error!(
+            SpanRange::from_tokens(label), "`{}` is not an attribute of `<{}>`", label, name;
+            help =? did_you_mean(&attribute, GLOBAL_ATTRIBUTES.iter().chain(attributes.iter()).copied())
+        );
+    }
+}
@@ -1,4 +1,13 @@
+mod aria;
 mod attrs;
+mod element;
+mod lint;
+mod listener;
+mod prefixed_map;
+mod style;
+mod suggestion;
+// Copied from Yew and kept unchanged, including parts which are not used here.
+#[allow(dead_code)]
 mod yew_macro;
 
 use proc_macro::TokenStream;
@@ -6,11 +15,58 @@ use quote::ToTokens;
 use syn::parse_macro_input;
 
 use crate::attrs::Attrs;
+use crate::style::Style;
 
 /// Macro to generate dynamic attributes.
+///
+/// A base [`Attrs`](https://docs.rs/yew-attrs/latest/yew_attrs/struct.Attrs.html) expression can be given after `..`
+/// as the last item, e.g. `attrs! { class="x" ..base }`. The other attributes are merged on top of the base.
+///
+/// Attributes can be included conditionally with `if`, `else if` and `else`, e.g.
+/// `attrs! { if active { aria-current="page" } }`. Conditions are evaluated like nested `if` expressions, before any
+/// values, and values of omitted attributes are not evaluated. Conditional classes are combined with the other classes. Attributes with an [`Option`] value are omitted if the value is [`None`]. A conditional attribute overrides an earlier attribute with the same name, and removes it if its value is [`None`] or `false`.
+///
+/// `data={map}` and `aria={map}` insert the entries of an `IntoIterator<Item = (K, V)>` as `data-K` and `aria-K`
+/// attributes, omitting entries with a [`None`] value. String literal keys of arrays and `vec!` are checked at compile
+/// time. A `data` value which converts into an attribute value, e.g. `data={url}`, still sets the `data` attribute of
+/// `object` elements, and `~data={url}` sets the `data` property.
+///
+/// With `attrs!(for button { ... })`, attributes and boolean attributes are checked against the content attributes of
+/// the HTML element, e.g. `href` is an error on a `button`. Global attributes, `data-*` and `aria-*` attributes,
+/// listeners and attributes applied as property are allowed on all elements. The base expression is not checked.
+///
+/// Literal values of `aria-*` attributes are checked against the WAI-ARIA 1.2 attributes of enumerated, boolean and
+/// numeric types. A warning is emitted for unknown `aria-*` attributes, e.g. a misspelled attribute like `aria-lable`,
+/// which can be silenced with `#[allow(deprecated)]` for attributes newer than WAI-ARIA 1.2.
+///
+/// Listeners for events other than Yew's built-in listeners, e.g. custom events of web components, are written as
+/// `on:sl-change={callback}`, with a callback receiving an `Event`. `on:touchstart|passive={callback}` creates a
+/// passive listener.
+///
+/// An `on*` attribute with a non-literal value which is close to a known listener name, e.g. `onClick={cb}` or
+/// `onclik={cb}`, is an error with the closest listener as suggestion.
+///
+/// With the `unknown-attribute-lint` feature, a warning is emitted for attributes which are not known HTML or SVG
+/// attributes, `data-*` or `aria-*` attributes or listeners, e.g. a misspelled attribute like `tittle`.
+///
+/// The `style` attribute accepts CSS declarations like [`style!`], e.g. `attrs! { style={ width: "10px", color: c } }`.
 #[proc_macro_error::proc_macro_error]
 #[proc_macro]
 pub fn attrs(input: TokenStream) -> TokenStream {
     let root = parse_macro_input!(input as Attrs);
     TokenStream::from(root.into_token_stream())
 }
+
+/// Macro to generate a `style` attribute value from CSS declarations.
+///
+/// Declarations are of the form `property: value` and separated by commas, e.g.
+/// `style! { width: format!("{w}px"), background-color: c, "--gap": "1rem" }`. Values can be any type which converts
+/// into an optional [`AttrValue`](https://docs.rs/yew/latest/yew/virtual_dom/type.AttrValue.html). Declarations
+/// with a [`None`] value are omitted. Evaluates to an `Option<AttrValue>`, which is [`None`] if all declarations are
+/// omitted. Declarations with only literal values are concatenated at compile time.
+#[proc_macro_error::proc_macro_error]
+#[proc_macro]
+pub fn style(input: TokenStream) -> TokenStream {
+    let root = parse_macro_input!(input as Style);
+    TokenStream::from(root.into_token_stream())
+}
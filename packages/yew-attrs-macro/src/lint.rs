@@ -0,0 +1,227 @@
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
+use crate::element;
+use crate::suggestion::unknown_name_warning;
+use crate::yew_macro::props::{ElementProps, Prop, BOOLEAN_SET, LISTENER_SET};
+
+// From: https://www.w3.org/TR/SVG2/attindex.html
+// Attributes which are also HTML attributes are only in `element::attributes`.
+static SVG_ATTRIBUTES: &[&str] = &[
+    "accumulate",
+    "additive",
+    "alignment-baseline",
+    "amplitude",
+    "attributeName",
+    "azimuth",
+    "baseFrequency",
+    "baseline-shift",
+    "begin",
+    "bias",
+    "by",
+    "calcMode",
+    "clip",
+    "clip-path",
+    "clip-rule",
+    "clipPathUnits",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "cursor",
+    "cx",
+    "cy",
+    "d",
+    "diffuseConstant",
+    "direction",
+    "display",
+    "divisor",
+    "dominant-baseline",
+    "dur",
+    "dx",
+    "dy",
+    "edgeMode",
+    "elevation",
+    "end",
+    "exponent",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "filter",
+    "filterUnits",
+    "flood-color",
+    "flood-opacity",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "fr",
+    "from",
+    "fx",
+    "fy",
+    "gradientTransform",
+    "gradientUnits",
+    "image-rendering",
+    "in",
+    "in2",
+    "intercept",
+    "k1",
+    "k2",
+    "k3",
+    "k4",
+    "kernelMatrix",
+    "kernelUnitLength",
+    "keyPoints",
+    "keySplines",
+    "keyTimes",
+    "lengthAdjust",
+    "letter-spacing",
+    "lighting-color",
+    "limitingConeAngle",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "markerHeight",
+    "markerUnits",
+    "markerWidth",
+    "mask",
+    "mask-type",
+    "maskContentUnits",
+    "maskUnits",
+    "mode",
+    "numOctaves",
+    "offset",
+    "opacity",
+    "operator",
+    "order",
+    "orient",
+    "origin",
+    "overflow",
+    "paint-order",
+    "path",
+    "pathLength",
+    "patternContentUnits",
+    "patternTransform",
+    "patternUnits",
+    "pointer-events",
+    "points",
+    "pointsAtX",
+    "pointsAtY",
+    "pointsAtZ",
+    "preserveAlpha",
+    "preserveAspectRatio",
+    "primitiveUnits",
+    "r",
+    "radius",
+    "refX",
+    "refY",
+    "repeatCount",
+    "repeatDur",
+    "restart",
+    "result",
+    "rotate",
+    "rx",
+    "ry",
+    "scale",
+    "seed",
+    "shape-rendering",
+    "side",
+    "spacing",
+    "specularConstant",
+    "specularExponent",
+    "spreadMethod",
+    "startOffset",
+    "stdDeviation",
+    "stitchTiles",
+    "stop-color",
+    "stop-opacity",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "surfaceScale",
+    "systemLanguage",
+    "tableValues",
+    "targetX",
+    "targetY",
+    "text-anchor",
+    "text-decoration",
+    "text-rendering",
+    "textLength",
+    "to",
+    "transform",
+    "transform-origin",
+    "unicode-bidi",
+    "values",
+    "vector-effect",
+    "version",
+    "viewBox",
+    "visibility",
+    "word-spacing",
+    "writing-mode",
+    "x",
+    "x1",
+    "x2",
+    "xChannelSelector",
+    "xlink:href",
+    "xml:lang",
+    "xml:space",
+    "xmlns",
+    "xmlns:xlink",
+    "y",
+    "y1",
+    "y2",
+    "yChannelSelector",
+    "z",
+    "zoomAndPan",
+];
+
+/// Warn about attributes which are not known HTML or SVG attributes, `data-*` or `aria-*` attributes or listeners.
+///
+/// Only enabled with the `unknown-attribute-lint` feature. Attributes applied as property are not checked.
+pub fn unknown_attributes(props: &ElementProps) -> TokenStream {
+    if !cfg!(feature = "unknown-attribute-lint") {
+        return TokenStream::new();
+    }
+
+    props
+        .attributes
+        .iter()
+        .filter(|prop| prop.directive.is_none())
+        .filter_map(|Prop { label, .. }| {
+            let name = label.to_string().to_ascii_lowercase();
+            if is_known(&name) {
+                return None;
+            }
+
+            let candidates = element::attributes()
+                .chain(SVG_ATTRIBUTES.iter().copied())
+                .chain(BOOLEAN_SET.iter().copied())
+                .chain(LISTENER_SET.iter().copied());
+
+            Some(unknown_name_warning(
+                label.span(),
+                "unknown_attribute",
+                format!("unknown attribute `{label}`"),
+                &name,
+                candidates,
+            ))
+        })
+        .collect()
+}
+
+fn is_known(name: &str) -> bool {
+    name.starts_with("data-")
+        || name.starts_with("aria-")
+        || element::attributes().any(|attribute| attribute == name)
+        || SVG_ATTRIBUTES
+            .iter()
+            .any(|attribute| attribute.eq_ignore_ascii_case(name))
+        || BOOLEAN_SET.contains(name)
+        || LISTENER_SET.contains(name)
+}
@@ -0,0 +1,28 @@
+use syn::Expr;
+
+use crate::suggestion::did_you_mean;
+use crate::yew_macro::props::{Prop, LISTENER_SET};
+
+/// Check that an attribute is not a misspelled listener, e.g. `onClick={cb}` or `onclik={cb}`.
+///
+/// Without this check, such a prop is treated as attribute and fails with a type error on its value. An `on*` prop with
+/// a non-literal value is reported if its name is close to a known listener, or if its value is a closure.
+pub fn check_listener_name(prop: &Prop) -> syn::Result<()> {
+    let label = prop.label.to_string();
+    let name = label.to_ascii_lowercase();
+
+    if prop.directive.is_some() || !name.starts_with("on") || matches!(prop.value, Expr::Lit(_)) {
+        return Ok(());
+    }
+
+    let message = match did_you_mean(&name, LISTENER_SET.iter().copied()) {
+        Some(help) => format!("unknown listener `{label}`, {help}"),
+        None if matches!(prop.value, Expr::Closure(_)) => format!(
+            "unknown listener `{label}`, use `on:{}` for listeners of other events",
+            &label[2..]
+        ),
+        None => return Ok(()),
+    };
+
+    Err(syn::Error::new_spanned(&prop.label, message))
+}
@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, Lit, LitStr, Token};
+
+use crate::yew_macro::props::Prop;
+
+/// Prefixes of the map shorthands `data={map}` and `aria={map}`.
+const PREFIXES: [&str; 2] = ["data", "aria"];
+
+/// Whether a prop is a map shorthand, i.e. `data` or `aria` with a non-literal value.
+///
+/// A literal value or the property directive (`~data={url}`) keeps `data` usable as attribute or property of `object`
+/// elements. A dynamic `data={url}` is still inserted as attribute if its value converts into an attribute value.
+pub fn is_prefixed_map(prop: &Prop) -> bool {
+    prop.label.extended.is_empty()
+        && PREFIXES.contains(&prop.label.name.to_string().as_str())
+        && prop.directive.is_none()
+        && !matches!(prop.value, Expr::Lit(_))
+}
+
+/// Check the literal keys of a map shorthand, e.g. `data={[("id", id)]}`.
+pub fn check_literal_keys(prop: &Prop) -> syn::Result<()> {
+    let prefix = prop.label.name.to_string();
+
+    for (key, _) in literal_entries(&prop.value) {
+        if let Some(message) = invalid_key_message(&prefix, &key.value()) {
+            return Err(syn::Error::new_spanned(&key, message));
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid_key_message(prefix: &str, key: &str) -> Option<String> {
+    if key.is_empty() {
+        return Some(format!("`{prefix}` key can't be empty"));
+    }
+    if let Some(rest) = key.strip_prefix(&format!("{prefix}-")) {
+        return Some(format!(
+            "`{prefix}` key `{key}` already starts with `{prefix}-`, use `{rest}` instead"
+        ));
+    }
+
+    key.chars()
+        .find(|c| c.is_ascii_uppercase() || c.is_whitespace() || "\"'>/=".contains(*c))
+        .map(|c| format!("invalid character `{c}` in `{prefix}` key `{key}`"))
+}
+
+/// Entries with a string literal key of an array, slice reference or `vec!` of tuples.
+pub fn literal_entries(expr: &Expr) -> Vec<(LitStr, Expr)> {
+    let elems: Vec<Expr> = match expr {
+        Expr::Array(array) => array.elems.iter().cloned().collect(),
+        Expr::Reference(reference) => return literal_entries(&reference.expr),
+        Expr::Macro(mac) if mac.mac.path.is_ident("vec") => mac
+            .mac
+            .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            .map(|elems| elems.into_iter().collect())
+            .unwrap_or_default(),
+        _ => vec![],
+    };
+
+    elems
+        .into_iter()
+        .filter_map(|elem| match elem {
+            Expr::Tuple(tuple) if tuple.elems.len() == 2 => {
+                let mut elems = tuple.elems.into_iter();
+                match (elems.next(), elems.next()) {
+                    (
+                        Some(Expr::Lit(ExprLit {
+                            lit: Lit::Str(key), ..
+                        })),
+                        Some(value),
+                    ) => Some((key, value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Insert the value of a map shorthand into `__yew_map`, omitting [`None`] values.
+///
+/// The value of `data={value}` is chosen by type: a value which converts into an attribute value sets the `data`
+/// attribute, e.g. of `object` elements, and other values are inserted as map.
+pub fn insert_tokens(prop: &Prop) -> TokenStream {
+    let name = prop.label.name.to_string();
+    let value = &prop.value;
+
+    if name == "data" {
+        let insert = quote_spanned! {value.span()=>
+            (&&::yew_attrs::prefixed_map::PrefixedValue::new(#value)).insert(__yew_map, #name);
+        };
+
+        // Only one of the traits is used, depending on the type of the value.
+        quote! {
+            {
+                #[allow(unused_imports)]
+                use ::yew_attrs::prefixed_map::{InsertAttribute as _, InsertEntries as _};
+                #insert
+            }
+        }
+    } else {
+        quote_spanned! {value.span()=>
+            ::yew_attrs::prefixed_map::InsertEntries::insert(
+                &::yew_attrs::prefixed_map::PrefixedValue::new(#value),
+                __yew_map,
+                #name,
+            );
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Brace;
+use syn::{braced, Expr, ExprLit, Lit, LitStr, Token};
+
+use crate::yew_macro::html_tree::HtmlDashedName;
+
+/// CSS declarations of the form `width: "10px", color: c`, used by `style!` and `style={ ... }` in `attrs!`.
+pub struct Style {
+    declarations: Punctuated<Declaration, Token![,]>,
+}
+
+struct Declaration {
+    property: LitStr,
+    value: Expr,
+}
+
+impl Parse for Declaration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let property = if input.peek(LitStr) {
+            input.parse::<LitStr>()?
+        } else {
+            input.parse::<HtmlDashedName>()?.to_lit_str()
+        };
+        input.parse::<Token![:]>()?;
+        let value = input.parse::<Expr>()?;
+
+        Ok(Self { property, value })
+    }
+}
+
+impl Parse for Style {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let declarations = Punctuated::<Declaration, Token![,]>::parse_terminated(input)?;
+
+        let mut properties = HashSet::new();
+        for declaration in &declarations {
+            if !properties.insert(declaration.property.value()) {
+                return Err(syn::Error::new_spanned(
+                    &declaration.property,
+                    format!(
+                        "`{}` can only be specified once",
+                        declaration.property.value()
+                    ),
+                ));
+            }
+        }
+
+        Ok(Self { declarations })
+    }
+}
+
+impl Style {
+    /// Whether the input starts with a braced group of CSS declarations, i.e. `{ property: ... }`.
+    pub fn peek_braced(input: ParseStream) -> bool {
+        input.peek(Brace) && Self::peek_declaration(&input.fork()).unwrap_or(false)
+    }
+
+    fn peek_declaration(input: ParseStream) -> syn::Result<bool> {
+        let content;
+        braced!(content in input);
+
+        let property = if content.peek(LitStr) {
+            content.parse::<LitStr>().is_ok()
+        } else {
+            content.peek(Ident::peek_any) && content.parse::<HtmlDashedName>().is_ok()
+        };
+
+        Ok(property && content.peek(Token![:]) && !content.peek(Token![::]))
+    }
+
+    /// Parse a braced group of CSS declarations into an expression of type `Option<AttrValue>`.
+    pub fn parse_braced(input: ParseStream) -> syn::Result<Expr> {
+        let content;
+        braced!(content in input);
+        let style = content.parse::<Style>()?;
+
+        Ok(style.into_expr())
+    }
+
+    /// Convert into an expression of type `Option<AttrValue>`, which is a string literal if all values are literals.
+    pub fn into_expr(self) -> Expr {
+        match self.to_lit_str() {
+            Some(lit) => Expr::Lit(ExprLit {
+                attrs: vec![],
+                lit: Lit::Str(lit),
+            }),
+            None => Expr::Verbatim(self.into_token_stream()),
+        }
+    }
+
+    /// Concatenate the declarations into a string literal if all values are string literals.
+    fn to_lit_str(&self) -> Option<LitStr> {
+        let declarations = self
+            .declarations
+            .iter()
+            .map(|Declaration { property, value }| match value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) => Some(format!("{}: {}", property.value(), value.value())),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        (!declarations.is_empty()).then(|| LitStr::new(&declarations.join("; "), self.span()))
+    }
+
+    fn span(&self) -> Span {
+        self.declarations
+            .first()
+            .map(|declaration| declaration.property.span())
+            .unwrap_or_else(Span::call_site)
+    }
+}
+
+impl ToTokens for Style {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(lit) = self.to_lit_str() {
+            tokens.extend(quote! {
+                ::std::option::Option::Some(::yew::virtual_dom::AttrValue::Static(#lit))
+            });
+            return;
+        }
+        if self.declarations.is_empty() {
+            tokens.extend(quote! {
+                ::std::option::Option::None::<::yew::virtual_dom::AttrValue>
+            });
+            return;
+        }
+
+        let pushes = self
+            .declarations
+            .iter()
+            .map(|Declaration { property, value }| {
+                let property = LitStr::new(&format!("{}: ", property.value()), property.span());
+                let value = quote_spanned! {value.span()=>
+                    ::yew::html::IntoPropValue::<
+                        ::std::option::Option<::yew::virtual_dom::AttrValue>
+                    >::into_prop_value(#value)
+                };
+
+                quote! {
+                    if let ::std::option::Option::Some(__yew_value) = #value {
+                        if !__yew_style.is_empty() {
+                            __yew_style.push_str("; ");
+                        }
+                        __yew_style.push_str(#property);
+                        __yew_style.push_str(&__yew_value);
+                    }
+                }
+            });
+
+        tokens.extend(quote! {
+            {
+                let mut __yew_style = ::std::string::String::new();
+                #(#pushes)*
+                if __yew_style.is_empty() {
+                    ::std::option::Option::None
+                } else {
+                    ::std::option::Option::Some(::yew::virtual_dom::AttrValue::from(__yew_style))
+                }
+            }
+        });
+    }
+}
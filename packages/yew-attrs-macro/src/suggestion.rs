@@ -0,0 +1,68 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote_spanned};
+
+/// Find the candidate closest to the given name, if it is close enough to be a likely typo.
+pub fn closest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        // Ties are broken by name, since candidates may come from a set without a stable order.
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Help message suggesting the candidate closest to the given name, if any.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    closest(name, candidates).map(|candidate| format!("did you mean `{candidate}`?"))
+}
+
+/// Warn about an unknown name, suggesting the candidate closest to it, if any.
+///
+/// The warning is emitted as use of a deprecated function with the given name, since proc macros can't emit warnings
+/// on stable. It can be silenced with `#[allow(deprecated)]`.
+pub fn unknown_name_warning<'a>(
+    span: Span,
+    function: &str,
+    message: String,
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> TokenStream {
+    let note = match did_you_mean(name, candidates) {
+        Some(help) => format!("{message}, {help}"),
+        None => message,
+    };
+    let function = format_ident!("{}", function, span = span);
+
+    quote_spanned! {span=>
+        {
+            #[deprecated(note = #note)]
+            fn #function() {}
+
+            #function();
+        }
+    }
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
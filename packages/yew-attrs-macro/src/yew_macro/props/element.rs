@@ -6,7 +6,9 @@ use once_cell::sync::Lazy;
 use syn::parse::{Parse, ParseStream};
 use syn::{Expr, ExprTuple};
 
-use super::{Prop, Props, SpecialProps};
+use super::{Prop, PropDirective, Props, SpecialProps};
+use crate::listener::check_listener_name;
+use crate::prefixed_map::{check_literal_keys, is_prefixed_map};
 
 pub enum ClassesForm {
     Tuple(ExprTuple),
@@ -25,6 +27,10 @@ pub struct ElementProps {
     pub attributes: Vec<Prop>,
     pub listeners: Vec<Prop>,
     pub classes: Option<ClassesForm>,
+    /// Classes in conditional groups, which are combined with `classes`.
+    pub conditional_classes: Vec<Prop>,
+    /// Map shorthands `data={map}` and `aria={map}`.
+    pub prefixed_maps: Vec<Prop>,
     pub booleans: Vec<Prop>,
     pub special: SpecialProps,
 }
@@ -33,8 +39,14 @@ impl Parse for ElementProps {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut props = input.parse::<Props>()?;
 
-        let listeners =
-            props.drain_filter(|prop| LISTENER_SET.contains(prop.label.to_string().as_str()));
+        let listeners = props.drain_filter(|prop| {
+            matches!(prop.directive, Some(PropDirective::Listener { .. }))
+                || LISTENER_SET.contains(prop.label.to_string().as_str())
+        });
+
+        for prop in props.iter() {
+            check_listener_name(prop)?;
+        }
 
         // Multiple listener attributes are allowed, but no others
         props.check_no_duplicates()?;
@@ -42,6 +54,13 @@ impl Parse for ElementProps {
         let booleans =
             props.drain_filter(|prop| BOOLEAN_SET.contains(prop.label.to_string().as_str()));
 
+        let prefixed_maps = props.drain_filter(is_prefixed_map);
+        for prop in prefixed_maps.iter() {
+            check_literal_keys(prop)?;
+        }
+
+        let conditional_classes = props
+            .drain_filter(|prop| prop.label.to_string() == "class" && !prop.conditions.is_empty());
         let classes = props
             .pop("class")
             .map(|prop| ClassesForm::from_expr(prop.value));
@@ -50,6 +69,8 @@ impl Parse for ElementProps {
         Ok(Self {
             attributes: props.prop_list.into_vec(),
             classes,
+            conditional_classes: conditional_classes.into_vec(),
+            prefixed_maps: prefixed_maps.into_vec(),
             listeners: listeners.into_vec(),
             booleans: booleans.into_vec(),
             special,
@@ -57,7 +78,7 @@ impl Parse for ElementProps {
     }
 }
 
-static BOOLEAN_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+pub static BOOLEAN_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     [
         // Living Standard
         // From: https://html.spec.whatwg.org/#attributes-3
@@ -93,7 +114,7 @@ static BOOLEAN_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .into()
 });
 
-static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+pub static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     [
         // Living Standard
         // From: https://html.spec.whatwg.org/multipage/webappapis.html#globaleventhandlers
@@ -2,17 +2,23 @@
 
 use std::convert::TryFrom;
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
-use proc_macro2::{Spacing, TokenTree};
+use proc_macro2::{Ident, Spacing, TokenTree};
 use syn::parse::{Parse, ParseBuffer, ParseStream};
 use syn::token::Brace;
 use syn::{braced, Block, Expr, ExprBlock, ExprMacro, ExprPath, ExprRange, Stmt, Token};
 
+use crate::style::Style;
 use crate::yew_macro::html_tree::HtmlDashedName;
 
 #[derive(Copy, Clone)]
 pub enum PropDirective {
     ApplyAsProperty(Token![~]),
+    /// Listener for the event named by the label, e.g. `on:sl-change={callback}` or `on:touchstart|passive={callback}`.
+    Listener {
+        passive: bool,
+    },
 }
 
 pub struct Prop {
@@ -20,6 +26,18 @@ pub struct Prop {
     pub label: HtmlDashedName,
     /// Punctuation between `label` and `value`.
     pub value: Expr,
+    /// Conditions of the enclosing conditional groups, e.g. `if active { ... }`, from outer to inner.
+    pub conditions: Vec<Condition>,
+}
+
+/// Condition of a conditional group `if condition { ... } else { ... }`.
+#[derive(Clone)]
+pub struct Condition {
+    /// Index of the condition in order of appearance, unique per macro invocation.
+    pub index: usize,
+    pub expr: Rc<Expr>,
+    /// Whether the prop is in the `else` branch.
+    pub negated: bool,
 }
 
 impl Parse for Prop {
@@ -28,6 +46,15 @@ impl Parse for Prop {
             .parse::<Token![~]>()
             .map(PropDirective::ApplyAsProperty)
             .ok();
+        if Self::peek_listener(input) {
+            if let Some(PropDirective::ApplyAsProperty(token)) = directive {
+                return Err(syn::Error::new_spanned(
+                    token,
+                    "listeners can't be applied as property",
+                ));
+            }
+            return Self::parse_listener(input);
+        }
         if input.peek(Brace) {
             Self::parse_shorthand_prop_assignment(input, directive)
         } else {
@@ -74,6 +101,56 @@ impl Prop {
             label,
             value: expr,
             directive,
+            conditions: Vec::new(),
+        })
+    }
+
+    /// Whether the input starts with `on:`, the prefix of a listener for an arbitrary event.
+    fn peek_listener(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<Ident>().is_ok_and(|ident| ident == "on")
+            && fork.peek(Token![:])
+            && !fork.peek(Token![::])
+    }
+
+    /// Parse a listener of the form `on:event|modifier={callback}`
+    fn parse_listener(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let label = input.parse::<HtmlDashedName>()?;
+
+        let mut passive = false;
+        while input.peek(Token![|]) {
+            input.parse::<Token![|]>()?;
+            let modifier = input.parse::<Ident>()?;
+            match modifier.to_string().as_str() {
+                "passive" => passive = true,
+                "capture" => {
+                    return Err(syn::Error::new_spanned(
+                        modifier,
+                        "`capture` is not supported, since Yew dispatches all listeners in bubbling order from a \
+                         single capturing listener on the application root",
+                    ))
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &modifier,
+                        format!("unknown listener modifier `{modifier}`, expected `passive`"),
+                    ))
+                }
+            }
+        }
+
+        input.parse::<Token![=]>().map_err(|_| {
+            syn::Error::new_spanned(&label, format!("`on:{label}` doesn't have a callback"))
+        })?;
+        let value = parse_prop_value(input)?;
+
+        Ok(Self {
+            label,
+            value,
+            directive: Some(PropDirective::Listener { passive }),
+            conditions: Vec::new(),
         })
     }
 
@@ -99,11 +176,16 @@ impl Prop {
             ));
         }
 
-        let value = parse_prop_value(input)?;
+        let value = if label.to_string() == "style" && Style::peek_braced(input) {
+            Style::parse_braced(input)?
+        } else {
+            parse_prop_value(input)?
+        };
         Ok(Self {
             label,
             value,
             directive,
+            conditions: Vec::new(),
         })
     }
 }
@@ -263,7 +345,8 @@ impl PropList {
         self.0.windows(2).filter_map(|pair| {
             let (a, b) = (&pair[0], &pair[1]);
 
-            if a.label == b.label {
+            // Conditional props may override earlier props, removing them if their value is `None` or `false`
+            if a.label == b.label && b.conditions.is_empty() {
                 Some(b)
             } else {
                 None
@@ -294,14 +377,78 @@ impl PropList {
 impl Parse for PropList {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut props: Vec<Prop> = Vec::new();
-        // Stop parsing props if a base expression preceded by `..` is reached
-        while !input.is_empty() && !input.peek(Token![..]) {
-            props.push(input.parse()?);
-        }
+        parse_props(input, &[], &mut props, &mut 0)?;
 
         Ok(Self::new(props))
     }
 }
+
+/// Parse props and conditional groups with the given conditions.
+fn parse_props(
+    input: ParseStream,
+    conditions: &[Condition],
+    props: &mut Vec<Prop>,
+    next_index: &mut usize,
+) -> syn::Result<()> {
+    // Stop parsing props if a base expression preceded by `..` is reached
+    while !input.is_empty() && !input.peek(Token![..]) {
+        if input.peek(Token![if]) {
+            parse_conditional(input, conditions, props, next_index)?;
+        } else {
+            let mut prop = input.parse::<Prop>()?;
+            prop.conditions = conditions.to_vec();
+            props.push(prop);
+        }
+    }
+
+    if !conditions.is_empty() && !input.is_empty() {
+        return Err(input.error("base expression is not allowed in conditional attributes"));
+    }
+
+    Ok(())
+}
+
+/// Parse a conditional group of the form `if condition { ... }`, optionally followed by `else { ... }` or
+/// `else if condition { ... }`.
+fn parse_conditional(
+    input: ParseStream,
+    conditions: &[Condition],
+    props: &mut Vec<Prop>,
+    next_index: &mut usize,
+) -> syn::Result<()> {
+    input.parse::<Token![if]>()?;
+    let expr = Rc::new(Expr::parse_without_eager_brace(input)?);
+    let index = *next_index;
+    *next_index += 1;
+
+    let branch = |negated: bool| {
+        let mut conditions = conditions.to_vec();
+        conditions.push(Condition {
+            index,
+            expr: expr.clone(),
+            negated,
+        });
+        conditions
+    };
+
+    let content;
+    braced!(content in input);
+    parse_props(&content, &branch(false), props, next_index)?;
+
+    if input.peek(Token![else]) {
+        input.parse::<Token![else]>()?;
+
+        if input.peek(Token![if]) {
+            parse_conditional(input, &branch(true), props, next_index)?;
+        } else {
+            let content;
+            braced!(content in input);
+            parse_props(&content, &branch(true), props, next_index)?;
+        }
+    }
+
+    Ok(())
+}
 impl Deref for PropList {
     type Target = [Prop];
 
@@ -51,3 +51,73 @@ async fn attrs_component() {
         rendered
     )
 }
+
+#[function_component]
+fn ToHtmlStringApp() -> Html {
+    html! {
+        <Button attrs={to_html_string_attrs()}>
+            {"Click"}
+        </Button>
+    }
+}
+
+fn to_html_string_attrs() -> Attrs {
+    attrs! {class="text-red" title="\"Click\" & <go>" disabled=true onclick={|_| {}}}
+}
+
+#[tokio::test]
+async fn attrs_component_to_html_string() {
+    let renderer = ServerRenderer::<ToHtmlStringApp>::new().hydratable(false);
+    let rendered = renderer.render().await;
+
+    assert_eq!(
+        format!(
+            "<button {}>Click</button>",
+            to_html_string_attrs().to_html_string()
+        ),
+        rendered
+    )
+}
+
+#[derive(PartialEq, Properties)]
+struct InputProps {
+    pub attrs: Attrs,
+}
+
+#[function_component]
+fn Input(props: &InputProps) -> Html {
+    props
+        .attrs
+        .clone()
+        .new_vtag("input", Default::default(), Default::default(), html! {})
+        .into()
+}
+
+#[function_component]
+fn ToHtmlStringInputApp() -> Html {
+    html! {
+        <>
+            <Input attrs={to_html_string_input_attrs(true)} />
+            <Input attrs={to_html_string_input_attrs(false)} />
+        </>
+    }
+}
+
+fn to_html_string_input_attrs(checked: bool) -> Attrs {
+    attrs! {type="checkbox" checked={checked} name="a"}
+}
+
+#[tokio::test]
+async fn attrs_component_to_html_string_input() {
+    let renderer = ServerRenderer::<ToHtmlStringInputApp>::new().hydratable(false);
+    let rendered = renderer.render().await;
+
+    assert_eq!(
+        format!(
+            "<input {}><input {}>",
+            to_html_string_input_attrs(true).to_html_string(),
+            to_html_string_input_attrs(false).to_html_string()
+        ),
+        rendered
+    )
+}
@@ -0,0 +1,11 @@
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { aria-live="polit" aria-hidden="yes" aria-level="one" aria-valuenow="1.5.0" };
+
+    attrs! { aria-relevant="additions txt" };
+
+    attrs! { aria={[("label", "Close"), ("expanded", "maybe")]} };
+}
+
+fn main() {}
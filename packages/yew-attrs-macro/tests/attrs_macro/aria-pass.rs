@@ -0,0 +1,24 @@
+use yew_attrs_macro::attrs;
+
+fn compile_pass() {
+    let label = "Close";
+
+    attrs! {
+        aria-label={label}
+        aria-hidden="true"
+        aria-checked="mixed"
+        aria-live="Polite"
+        aria-level="2"
+        aria-valuenow="1.5"
+        aria-relevant="additions text"
+        aria-describedby="a b"
+        aria={[("expanded", "false")]}
+    };
+}
+
+#[allow(deprecated)]
+fn compile_pass_unknown() {
+    attrs! { aria-description="Closes the dialog" aria={[("braillelabel", "Close")]} };
+}
+
+fn main() {}
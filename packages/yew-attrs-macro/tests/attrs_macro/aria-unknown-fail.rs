@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { aria-lable="Close" };
+
+    attrs! { aria-foo="bar" };
+
+    attrs! { aria={[("lable", "Close"), ("expanded", "false")]} };
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { class="text-red" .."base" };
+}
+
+fn main() {}
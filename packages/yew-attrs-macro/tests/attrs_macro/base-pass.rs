@@ -0,0 +1,16 @@
+use yew_attrs::Attrs;
+use yew_attrs_macro::attrs;
+
+fn compile_pass() {
+    let base = Attrs::default();
+    let on_click = |_| {};
+
+    _ = attrs! {
+        class="text-red"
+        onclick={on_click}
+        ..base
+    };
+    _ = attrs! { ..attrs! { id="a" } };
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use yew_attrs::Attrs;
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    let base = Attrs::default();
+    attrs! { if true { class="text-red" ..base } };
+
+    attrs! { if "true" { class="text-red" } };
+}
+
+fn main() {}
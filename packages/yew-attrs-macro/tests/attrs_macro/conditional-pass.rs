@@ -0,0 +1,18 @@
+use yew_attrs_macro::attrs;
+
+fn compile_pass() {
+    let active = true;
+    let disabled = false;
+
+    attrs! {
+        if active {
+            aria-current="page"
+        } else if disabled {
+            aria-disabled="true"
+        } else {
+            class="text-red"
+        }
+    };
+}
+
+fn main() {}
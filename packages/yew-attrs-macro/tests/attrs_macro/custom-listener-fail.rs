@@ -0,0 +1,13 @@
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { on:sl-change|capture={|_| {}} };
+
+    attrs! { on:sl-change|once={|_| {}} };
+
+    attrs! { ~on:sl-change={|_| {}} };
+
+    attrs! { on:sl-change };
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use yew::{Callback, Event};
+use yew_attrs_macro::attrs;
+
+fn compile_pass() {
+    let on_change = Callback::from(|_: Event| {});
+
+    _ = attrs! {
+        on:sl-change={on_change}
+        on:touchstart|passive={|_| {}}
+        on:my-event={Some(|_: Event| {})}
+    };
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs!(for button { href="/" disabled=true });
+
+    attrs!(for a { hreff="/" disabled=true checked=false });
+
+    attrs!(for buton { type="submit" });
+
+    attrs!(for sl-button { variant="primary" });
+
+    attrs!(for div { id="a" } class="b");
+}
+
+fn main() {}
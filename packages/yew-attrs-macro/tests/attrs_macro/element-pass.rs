@@ -0,0 +1,29 @@
+use yew_attrs_macro::attrs;
+
+fn compile_pass() {
+    let on_click = |_| {};
+
+    _ = attrs!(for a {
+        href="/"
+        target="_blank"
+        class="link"
+        hidden=false
+        data-id="1"
+        aria-current="page"
+        ~custom="a"
+        onclick={on_click}
+        if true {
+            rel="noopener"
+        }
+    });
+
+    _ = attrs!(for input { type="checkbox" checked=true disabled=false name="a" });
+
+    _ = attrs!(for textarea { value="x" rows="3" });
+
+    _ = attrs!(for select { value="x" multiple=true });
+
+    _ = attrs! { for="a" };
+}
+
+fn main() {}
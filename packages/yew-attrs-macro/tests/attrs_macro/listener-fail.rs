@@ -0,0 +1,14 @@
+use yew::Callback;
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    let on_click = Callback::from(|_| {});
+
+    attrs! { onClick={on_click.clone()} };
+
+    attrs! { onclik={on_click} };
+
+    attrs! { onfoobar={|_| {}} };
+}
+
+fn main() {}
@@ -6,6 +6,14 @@ fn compile_pass() {
     _ = attrs! {
         onclick={on_click}
     };
+
+    let one = "1";
+
+    _ = attrs! {
+        one={one}
+        onclik="alert(1)"
+        ~onClick={"property"}
+    };
 }
 
 fn main() {}
@@ -0,0 +1,11 @@
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { data={[("userId", "1")]} };
+
+    attrs! { aria={&[("aria-label", "Close")]} };
+
+    attrs! { data={vec![("", "1")]} };
+}
+
+fn main() {}
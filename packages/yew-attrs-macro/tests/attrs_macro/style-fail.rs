@@ -0,0 +1,9 @@
+use yew_attrs_macro::{attrs, style};
+
+fn compile_fail() {
+    attrs! { style={ width: "1px", width: "2px" } };
+
+    style! { width: 1 };
+}
+
+fn main() {}
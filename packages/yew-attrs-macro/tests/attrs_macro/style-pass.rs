@@ -0,0 +1,11 @@
+use yew_attrs_macro::{attrs, style};
+
+fn compile_pass() {
+    let color = Some("red");
+
+    attrs! { style={ width: "10px", color: color } };
+    attrs! { style={style! { width: "10px", color: color }} };
+    attrs! { style={ "--gap": format!("{}rem", 1) } };
+}
+
+fn main() {}
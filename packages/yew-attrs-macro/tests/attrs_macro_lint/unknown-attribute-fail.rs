@@ -0,0 +1,23 @@
+#![deny(deprecated)]
+
+use yew_attrs_macro::attrs;
+
+fn compile_fail() {
+    attrs! { onclik="alert(1)" };
+
+    attrs! { tittle="a" foo="b" };
+
+    attrs! {
+        id="a"
+        class="b"
+        disabled=true
+        data-x="c"
+        aria-label="d"
+        http-equiv="e"
+        TabIndex="0"
+        ~custom="f"
+        onclick={|_| {}}
+    };
+}
+
+fn main() {}
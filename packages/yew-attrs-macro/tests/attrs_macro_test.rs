@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{cell::Cell, rc::Rc};
 
 use indexmap::IndexMap;
 use yew::{
@@ -16,6 +16,14 @@ fn attrs_macro() {
     t.compile_fail("tests/attrs_macro/*-fail.rs");
 }
 
+#[cfg(feature = "unknown-attribute-lint")]
+#[test]
+fn attrs_macro_lint() {
+    let t = trybuild::TestCases::new();
+
+    t.compile_fail("tests/attrs_macro_lint/*-fail.rs");
+}
+
 #[test]
 fn attrs_static() {
     let attrs = attrs! {
@@ -134,3 +142,399 @@ fn attrs_listeners() {
         }
     }
 }
+
+#[test]
+fn attrs_base() {
+    let base = attrs! {
+        id="a"
+        class="flex"
+        required=true
+    };
+
+    let attrs = attrs! {
+        id="b"
+        class="text-red"
+        ..base
+    };
+
+    assert_eq!(
+        Attrs::new(
+            Attributes::IndexMap(IndexMap::from([
+                (
+                    AttrValue::Static("id"),
+                    (AttrValue::Static("b"), ApplyAttributeAs::Attribute)
+                ),
+                (
+                    AttrValue::Static("required"),
+                    (AttrValue::Static("required"), ApplyAttributeAs::Attribute)
+                ),
+                (
+                    AttrValue::Static("class"),
+                    (
+                        AttrValue::from("flex text-red".to_string()),
+                        ApplyAttributeAs::Attribute
+                    )
+                ),
+            ])),
+            Listeners::None
+        ),
+        attrs
+    );
+}
+
+#[test]
+fn attrs_checked() {
+    let checked = false;
+
+    assert_eq!(None, attrs! { required=true }.checked);
+    assert_eq!(Some(true), attrs! { checked=true }.checked);
+    assert_eq!(Some(false), attrs! { checked=false }.checked);
+    assert_eq!(Some(false), attrs! { checked={checked} }.checked);
+    assert_eq!(
+        Attrs::new(Attributes::IndexMap(IndexMap::default()), Listeners::None).with_checked(false),
+        attrs! { checked=false }
+    );
+}
+
+#[test]
+fn attrs_dynamic_false() {
+    let hidden = false;
+
+    assert_eq!(
+        Attrs::new(Attributes::IndexMap(IndexMap::default()), Listeners::None),
+        attrs! { hidden={hidden} }
+    );
+}
+
+#[test]
+fn attrs_conditional() {
+    let attrs = |active: bool, disabled: bool| {
+        attrs! {
+            id="a"
+            if active {
+                aria-current="page"
+                class="active"
+                hidden=true
+            } else if disabled {
+                aria-disabled="true"
+            } else {
+                title="b"
+            }
+            class="flex"
+        }
+    };
+
+    let entries = |attrs: &Attrs| {
+        attrs
+            .iter()
+            .map(|(name, value, _)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+    };
+    let expected = |entries: &[(&str, &str)]| {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        expected(&[
+            ("id", "a"),
+            ("aria-current", "page"),
+            ("hidden", "hidden"),
+            ("class", "flex active"),
+        ]),
+        entries(&attrs(true, true))
+    );
+    assert_eq!(
+        expected(&[("id", "a"), ("aria-disabled", "true"), ("class", "flex")]),
+        entries(&attrs(false, true))
+    );
+    assert_eq!(
+        expected(&[("id", "a"), ("title", "b"), ("class", "flex")]),
+        entries(&attrs(false, false))
+    );
+}
+
+#[test]
+fn attrs_conditional_nested() {
+    let attrs = |a: bool, b: bool| {
+        attrs! {
+            if a {
+                if b {
+                    title="ab"
+                } else {
+                    title="a"
+                }
+            }
+        }
+    };
+
+    assert_eq!(Some("ab"), attrs(true, true).get("title"));
+    assert_eq!(Some("a"), attrs(true, false).get("title"));
+    assert_eq!(None, attrs(false, true).get("title"));
+}
+
+#[test]
+fn attrs_conditional_override() {
+    let attrs = |active: bool| attrs! { title="a" if active { title="b" } };
+
+    assert_eq!(Some("a"), attrs(false).get("title"));
+    assert_eq!(Some("b"), attrs(true).get("title"));
+}
+
+#[test]
+fn attrs_conditional_override_remove() {
+    let attrs = |active: bool| attrs! { disabled=true if active { disabled=false } };
+
+    assert_eq!(Some("disabled"), attrs(false).get("disabled"));
+    assert!(!attrs(true).contains("disabled"));
+
+    let attrs = |active: bool| attrs! { id="a" if active { id={None::<String>} } };
+
+    assert_eq!(Some("a"), attrs(false).get("id"));
+    assert!(!attrs(true).contains("id"));
+
+    let attrs = |active: bool| attrs! { hidden=true if active { hidden={!active} } };
+
+    assert_eq!(Some("hidden"), attrs(false).get("hidden"));
+    assert!(!attrs(true).contains("hidden"));
+}
+
+#[test]
+fn attrs_conditional_not_evaluated() {
+    let mut evaluated = 0;
+    let mut value = || {
+        evaluated += 1;
+        "page"
+    };
+
+    let attrs = attrs! { if false { aria-current={value()} } };
+
+    assert!(attrs.is_empty());
+    assert_eq!(0, evaluated);
+}
+
+#[test]
+fn attrs_checked_evaluated_once() {
+    let evaluated = Cell::new(0);
+    let checked = |value: bool| {
+        evaluated.set(evaluated.get() + 1);
+        value
+    };
+
+    let attrs = attrs! { checked={checked(true)} };
+    assert_eq!(Some("checked"), attrs.get("checked"));
+    assert_eq!(Some(true), attrs.checked);
+    assert_eq!(1, evaluated.get());
+
+    let attrs = attrs! { checked={checked(false)} };
+    assert!(!attrs.contains("checked"));
+    assert_eq!(Some(false), attrs.checked);
+    assert_eq!(2, evaluated.get());
+
+    let attrs = |active: bool| attrs! { if active { checked={checked(true)} } };
+    assert_eq!(None, attrs(false).checked);
+    assert_eq!(2, evaluated.get());
+    assert_eq!(Some(true), attrs(true).checked);
+    assert_eq!(3, evaluated.get());
+}
+
+#[test]
+fn attrs_conditional_guarded() {
+    let attrs =
+        |user: Option<u32>| attrs! { if user.is_some() { if user.unwrap() > 3 { title="admin" } } };
+
+    assert!(attrs(None).is_empty());
+    assert!(attrs(Some(3)).is_empty());
+    assert_eq!(Some("admin"), attrs(Some(4)).get("title"));
+
+    let evaluated = Cell::new(0);
+    let disabled = || {
+        evaluated.set(evaluated.get() + 1);
+        true
+    };
+    let attrs = |active: bool| {
+        attrs! {
+            if active {
+                aria-current="page"
+            } else if disabled() {
+                aria-disabled="true"
+            }
+        }
+    };
+
+    assert_eq!(Some("page"), attrs(true).get("aria-current"));
+    assert_eq!(0, evaluated.get());
+    assert_eq!(Some("true"), attrs(false).get("aria-disabled"));
+    assert_eq!(1, evaluated.get());
+}
+
+#[test]
+fn attrs_conditional_listeners_and_checked() {
+    let attrs = |active: bool| {
+        attrs! {
+            if active {
+                onclick={|_| {}}
+                checked=true
+            } else {
+                checked=false
+            }
+        }
+    };
+
+    assert!(attrs(true).has_listener(&ListenerKind::onclick));
+    assert_eq!(Some(true), attrs(true).checked);
+    assert!(!attrs(false).has_listener(&ListenerKind::onclick));
+    assert_eq!(Some(false), attrs(false).checked);
+}
+
+#[test]
+fn attrs_option() {
+    let title: Option<&str> = None;
+    let label = Some("Close");
+
+    assert_eq!(
+        Attrs::new(
+            Attributes::IndexMap(IndexMap::from([(
+                AttrValue::Static("aria-label"),
+                (AttrValue::Static("Close"), ApplyAttributeAs::Attribute)
+            )])),
+            Listeners::None
+        ),
+        attrs! { title={title} aria-label={label} }
+    );
+}
+
+#[test]
+fn attrs_style_static() {
+    assert_eq!(
+        Attrs::new(
+            Attributes::IndexMap(IndexMap::from([(
+                AttrValue::Static("style"),
+                (
+                    AttrValue::Static("width: 10px; background-color: red; --gap: 1rem"),
+                    ApplyAttributeAs::Attribute
+                )
+            )])),
+            Listeners::None
+        ),
+        attrs! { style={ width: "10px", background-color: "red", "--gap": "1rem", } }
+    );
+}
+
+#[test]
+fn attrs_style_dynamic() {
+    let width = 10;
+    let color: Option<&str> = None;
+    let opacity = Some("0.5");
+
+    let attrs = attrs! {
+        style={ width: format!("{width}px"), color: color, opacity: opacity }
+    };
+    assert_eq!(Some("width: 10px; opacity: 0.5"), attrs.get("style"));
+
+    let attrs = attrs! { id="a" style={ color: color } };
+    assert_eq!(None, attrs.get("style"));
+}
+
+#[test]
+fn attrs_style_merge() {
+    let base = attrs! { style="color: blue; height: 1px" };
+    let color = "red";
+
+    assert_eq!(
+        Some("color: red; height: 1px; width: 2px"),
+        attrs! { style={ color: color, width: "2px" } ..base }.get("style")
+    );
+}
+
+#[test]
+fn style_macro() {
+    let color = Some("red");
+    let hidden: Option<String> = None;
+
+    assert_eq!(
+        Some(AttrValue::Static("width: 10px")),
+        yew_attrs_macro::style! { width: "10px" }
+    );
+    assert_eq!(
+        Some(AttrValue::from("color: red".to_string())),
+        yew_attrs_macro::style! { color: color, display: hidden }
+    );
+    assert_eq!(None, yew_attrs_macro::style! {});
+}
+
+#[test]
+fn attrs_prefixed_maps() {
+    let data = IndexMap::from([("id", "1"), ("user-name", "a")]);
+    let label: Option<&str> = None;
+
+    let attrs = attrs! {
+        id="a"
+        data={data}
+        aria={vec![("label", label), ("expanded", Some("true"))]}
+    };
+
+    assert_eq!(
+        vec![
+            ("id", "a"),
+            ("data-id", "1"),
+            ("data-user-name", "a"),
+            ("aria-expanded", "true"),
+        ],
+        attrs
+            .iter()
+            .map(|(name, value, _)| (name, value))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn attrs_prefixed_maps_conditional() {
+    let attrs = |active: bool| attrs! { if active { data={[("state", "active")]} } };
+
+    assert_eq!(Some("active"), attrs(true).get("data-state"));
+    assert!(attrs(false).is_empty());
+}
+
+#[test]
+fn attrs_data_attribute() {
+    let url = "movie.swf";
+
+    assert_eq!(Some("movie.swf"), attrs! { data="movie.swf" }.get("data"));
+    assert_eq!(
+        Some(("movie.swf", ApplyAttributeAs::Property)),
+        attrs! { ~data={url} }.get_with_apply_as("data")
+    );
+
+    let url = String::from("movie.swf");
+    let attrs = attrs! { type="application/x-shockwave-flash" data={url.clone()} };
+    assert_eq!(
+        Some(("movie.swf", ApplyAttributeAs::Attribute)),
+        attrs.get_with_apply_as("data")
+    );
+    assert_eq!(
+        "type=\"application/x-shockwave-flash\" data=\"movie.swf\"",
+        attrs.to_html_string()
+    );
+    assert_eq!(Some("movie.swf"), attrs! { data={Some(url)} }.get("data"));
+    assert!(attrs! { data={None::<String>} }.is_empty());
+    assert_eq!(
+        Some("1"),
+        attrs! { data={vec![("id", "1")]} }.get("data-id")
+    );
+}
+
+#[test]
+fn attrs_element() {
+    let base = attrs! { title="b" };
+    let disabled = true;
+
+    assert_eq!(
+        attrs! { type="submit" class="a" disabled={disabled} data-id="1" aria-label="c" ..base.clone() },
+        attrs!(for button { type="submit" class="a" disabled={disabled} data-id="1" aria-label="c" ..base })
+    );
+    assert_eq!(attrs! { for="a" }, attrs!(for label { for="a" }));
+    assert!(attrs!(for div { onclick={|_| {}} }).has_listener(&ListenerKind::onclick));
+}
@@ -0,0 +1,131 @@
+//! Map-like access to the attributes of [`Attrs`].
+
+use indexmap::map::Entry;
+use yew::{
+    virtual_dom::{ApplyAttributeAs, Attributes},
+    AttrValue,
+};
+
+use crate::Attrs;
+
+impl Attrs {
+    /// Get the value of an attribute.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_with_apply_as(name).map(|(value, _)| value)
+    }
+
+    /// Get the value of an attribute and whether it is applied as attribute or property.
+    pub fn get_with_apply_as(&self, name: &str) -> Option<(&str, ApplyAttributeAs)> {
+        match &self.attributes {
+            Attributes::Static(attributes) => attributes
+                .iter()
+                .find(|(key, _, _)| *key == name)
+                .map(|(_, value, apply_as)| (*value, *apply_as)),
+            Attributes::Dynamic { keys, values } => keys
+                .iter()
+                .zip(values.iter())
+                .find(|(key, _)| **key == name)
+                .and_then(|(_, value)| value.as_ref())
+                .map(|(value, apply_as)| (value.as_str(), *apply_as)),
+            Attributes::IndexMap(map) => map
+                .get(name)
+                .map(|(value, apply_as)| (value.as_str(), *apply_as)),
+        }
+    }
+
+    /// Whether an attribute is set.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get_with_apply_as(name).is_some()
+    }
+
+    /// Iterate over the names, values and [`ApplyAttributeAs`] of all set attributes in order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (&str, &str, ApplyAttributeAs)> + '_> {
+        match &self.attributes {
+            Attributes::Static(attributes) => Box::new(
+                attributes
+                    .iter()
+                    .map(|(key, value, apply_as)| (*key, *value, *apply_as)),
+            ),
+            Attributes::Dynamic { keys, values } => {
+                Box::new(keys.iter().zip(values.iter()).filter_map(|(key, value)| {
+                    value
+                        .as_ref()
+                        .map(|(value, apply_as)| (*key, value.as_str(), *apply_as))
+                }))
+            }
+            Attributes::IndexMap(map) => Box::new(
+                map.iter()
+                    .map(|(key, (value, apply_as))| (key.as_str(), value.as_str(), *apply_as)),
+            ),
+        }
+    }
+
+    /// Number of set attributes.
+    pub fn len(&self) -> usize {
+        match &self.attributes {
+            Attributes::Static(attributes) => attributes.len(),
+            Attributes::Dynamic { values, .. } => values.iter().flatten().count(),
+            Attributes::IndexMap(map) => map.len(),
+        }
+    }
+
+    /// Whether no attributes are set.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Insert an attribute, returning the previous value.
+    ///
+    /// An existing attribute keeps its position.
+    pub fn insert(
+        &mut self,
+        name: impl Into<AttrValue>,
+        value: impl Into<AttrValue>,
+    ) -> Option<AttrValue> {
+        self.insert_as(name, value, ApplyAttributeAs::Attribute)
+    }
+
+    /// Insert an attribute which is applied as attribute or property, returning the previous value.
+    ///
+    /// An existing attribute keeps its position.
+    pub fn insert_as(
+        &mut self,
+        name: impl Into<AttrValue>,
+        value: impl Into<AttrValue>,
+        apply_as: ApplyAttributeAs,
+    ) -> Option<AttrValue> {
+        self.attributes
+            .get_mut_index_map()
+            .insert(name.into(), (value.into(), apply_as))
+            .map(|(value, _)| value)
+    }
+
+    /// Remove an attribute, returning its value.
+    ///
+    /// The order of the other attributes is preserved.
+    pub fn remove(&mut self, name: &str) -> Option<AttrValue> {
+        if !self.contains(name) {
+            return None;
+        }
+
+        self.attributes
+            .get_mut_index_map()
+            .shift_remove(name)
+            .map(|(value, _)| value)
+    }
+
+    /// Retain only the attributes for which the predicate returns `true`.
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &AttrValue, ApplyAttributeAs) -> bool) {
+        self.attributes
+            .get_mut_index_map()
+            .retain(|key, (value, apply_as)| f(key, value, *apply_as));
+    }
+
+    /// Get the entry of an attribute for in-place manipulation.
+    pub fn entry(
+        &mut self,
+        name: impl Into<AttrValue>,
+    ) -> Entry<'_, AttrValue, (AttrValue, ApplyAttributeAs)> {
+        self.attributes.get_mut_index_map().entry(name.into())
+    }
+}
@@ -0,0 +1,139 @@
+//! Builder for [`Attrs`].
+
+use std::rc::Rc;
+
+use yew::{
+    html::{Classes, IntoPropValue},
+    virtual_dom::{ApplyAttributeAs, Listener, Listeners},
+    AttrValue,
+};
+
+use crate::{
+    merge::{MergeStrategy, TokenList},
+    Attrs,
+};
+
+/// Builder for [`Attrs`], following the semantics of the `attrs!` macro.
+///
+/// # Example
+/// ```
+/// use yew::prelude::*;
+/// use yew_attrs::{attrs, listeners::onclick, Attrs};
+///
+/// let on_click = Callback::from(|_: MouseEvent| {});
+///
+/// let attrs = Attrs::builder()
+///     .attr("id", "a")
+///     .bool_attr("disabled", true)
+///     .class("text-red")
+///     .property("value", "b")
+///     .listener(onclick(on_click.clone()))
+///     .build();
+///
+/// assert_eq!(
+///     attrs! { id="a" disabled=true class="text-red" ~value="b" onclick={on_click} }.attributes,
+///     attrs.attributes,
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct AttrsBuilder {
+    attrs: Attrs,
+}
+
+impl AttrsBuilder {
+    /// Create a new [`AttrsBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set an attribute. The attribute is omitted if the value is [`None`].
+    pub fn attr(
+        self,
+        name: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) -> Self {
+        self.insert(name, value.into_prop_value(), ApplyAttributeAs::Attribute)
+    }
+
+    /// Set a property. The property is omitted if the value is [`None`].
+    pub fn property(
+        self,
+        name: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) -> Self {
+        self.insert(name, value.into_prop_value(), ApplyAttributeAs::Property)
+    }
+
+    /// Set a boolean attribute. The attribute is set to its name if `true` and omitted if `false`.
+    ///
+    /// For `checked`, the checked state is also set, like [`Attrs::with_checked`].
+    pub fn bool_attr(mut self, name: impl Into<AttrValue>, value: bool) -> Self {
+        let name = name.into();
+
+        if name == "checked" {
+            self.attrs.checked = Some(value);
+        }
+
+        let value = value.then(|| name.clone());
+        self.insert(name, value, ApplyAttributeAs::Attribute)
+    }
+
+    /// Add classes. Classes are combined with previously added classes and omitted if empty.
+    pub fn class(mut self, classes: impl Into<Classes>) -> Self {
+        let classes: Option<AttrValue> = classes.into().into_prop_value();
+
+        if let Some(classes) = classes {
+            let classes = match self.attrs.remove("class") {
+                Some(existing) => TokenList.merge(existing, classes),
+                None => classes,
+            };
+            self.attrs.insert("class", classes);
+        }
+
+        self
+    }
+
+    /// Add a listener, e.g. one created with the functions from [`listeners`](crate::listeners).
+    pub fn listener(mut self, listener: Option<Rc<dyn Listener>>) -> Self {
+        self.attrs.listeners = match std::mem::take(&mut self.attrs.listeners) {
+            Listeners::None => Listeners::Pending(Box::new([listener])),
+            Listeners::Pending(listeners) => {
+                let mut listeners = listeners.into_vec();
+                listeners.push(listener);
+                Listeners::Pending(listeners.into_boxed_slice())
+            }
+        };
+
+        self
+    }
+
+    /// Build the [`Attrs`].
+    pub fn build(self) -> Attrs {
+        self.attrs
+    }
+
+    fn insert(
+        mut self,
+        name: impl Into<AttrValue>,
+        value: Option<AttrValue>,
+        apply_as: ApplyAttributeAs,
+    ) -> Self {
+        match value {
+            Some(value) => {
+                self.attrs.insert_as(name, value, apply_as);
+            }
+            None => {
+                self.attrs.remove(&name.into());
+            }
+        }
+
+        self
+    }
+}
+
+impl Attrs {
+    /// Create a new [`AttrsBuilder`].
+    pub fn builder() -> AttrsBuilder {
+        AttrsBuilder::new()
+    }
+}
@@ -0,0 +1,314 @@
+//! Conversion between [`Attrs`] and HTML attribute syntax.
+
+use indexmap::IndexMap;
+use yew::{
+    virtual_dom::{ApplyAttributeAs, Attributes},
+    AttrValue,
+};
+
+use crate::{Attrs, AttrsError};
+
+/// Boolean attributes, which are set to their name if present. Same as `BOOLEAN_SET` in `yew-attrs-macro`.
+pub(crate) const BOOLEAN_ATTRIBUTES: [&str; 26] = [
+    // Living Standard
+    // From: https://html.spec.whatwg.org/#attributes-3
+    // where `Value` = Boolean attribute
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+    "truespeed",
+    // Not-yet-standardized
+    "webkitdirectory",
+];
+
+/// SVG attributes with uppercase letters, which the HTML parser restores after lowercasing attribute names.
+// From: https://html.spec.whatwg.org/#adjust-svg-attributes
+const SVG_ATTRIBUTES: [&str; 58] = [
+    "attributeName",
+    "attributeType",
+    "baseFrequency",
+    "baseProfile",
+    "calcMode",
+    "clipPathUnits",
+    "diffuseConstant",
+    "edgeMode",
+    "filterUnits",
+    "glyphRef",
+    "gradientTransform",
+    "gradientUnits",
+    "kernelMatrix",
+    "kernelUnitLength",
+    "keyPoints",
+    "keySplines",
+    "keyTimes",
+    "lengthAdjust",
+    "limitingConeAngle",
+    "markerHeight",
+    "markerUnits",
+    "markerWidth",
+    "maskContentUnits",
+    "maskUnits",
+    "numOctaves",
+    "pathLength",
+    "patternContentUnits",
+    "patternTransform",
+    "patternUnits",
+    "pointsAtX",
+    "pointsAtY",
+    "pointsAtZ",
+    "preserveAlpha",
+    "preserveAspectRatio",
+    "primitiveUnits",
+    "refX",
+    "refY",
+    "repeatCount",
+    "repeatDur",
+    "requiredExtensions",
+    "requiredFeatures",
+    "specularConstant",
+    "specularExponent",
+    "spreadMethod",
+    "startOffset",
+    "stdDeviation",
+    "stitchTiles",
+    "surfaceScale",
+    "systemLanguage",
+    "tableValues",
+    "targetX",
+    "targetY",
+    "textLength",
+    "viewBox",
+    "viewTarget",
+    "xChannelSelector",
+    "yChannelSelector",
+    "zoomAndPan",
+];
+
+impl Attrs {
+    /// Parse [`Attrs`] from HTML attribute syntax, e.g. `class="a" disabled data-x='1'`.
+    ///
+    /// Values can be double-quoted, single-quoted or unquoted and character references are decoded. Boolean attributes
+    /// are set to their name like in the `attrs!` macro, regardless of their value. Other attributes without a value
+    /// are set to an empty string.
+    ///
+    /// Attribute names are ASCII case-insensitive and lowercased, except for SVG attributes like `viewBox`, which are
+    /// adjusted like the HTML parser does for SVG elements.
+    ///
+    /// Returns an error for malformed input, duplicate attributes and inline event handlers (e.g. `onclick`), which
+    /// can't be converted to listeners.
+    pub fn from_html_str(input: &str) -> Result<Attrs, AttrsError> {
+        let mut parser = Parser { input, position: 0 };
+        let mut attributes = IndexMap::new();
+        let mut checked = None;
+
+        loop {
+            let separated = parser.skip_whitespace();
+            let Some(c) = parser.peek() else {
+                break;
+            };
+            if !separated && !attributes.is_empty() {
+                return Err(
+                    parser.error(format!("expected whitespace before attribute, found `{c}`"))
+                );
+            }
+
+            let start = parser.position;
+            let name = parser.take_while(is_name_char);
+            if name.is_empty() {
+                return Err(parser.error(format!("expected attribute name, found `{c}`")));
+            }
+            if is_event_handler(name) {
+                parser.position = start;
+                return Err(parser.error(format!(
+                    "inline event handler `{name}` is not supported, use a listener instead"
+                )));
+            }
+
+            let boolean = BOOLEAN_ATTRIBUTES
+                .iter()
+                .find(|boolean| boolean.eq_ignore_ascii_case(name));
+            let svg = SVG_ATTRIBUTES
+                .iter()
+                .find(|svg| svg.eq_ignore_ascii_case(name));
+            let key = match boolean.or(svg) {
+                Some(known) => AttrValue::Static(known),
+                None => AttrValue::from(name.to_ascii_lowercase()),
+            };
+            if attributes.contains_key(&key) {
+                parser.position = start;
+                return Err(parser.error(format!("duplicate attribute `{name}`")));
+            }
+
+            let value = parser.parse_value(name)?;
+            let value = match (boolean, value) {
+                (Some(_), _) => key.clone(),
+                (None, Some(value)) => {
+                    AttrValue::from(html_escape::decode_html_entities(value).into_owned())
+                }
+                (None, None) => AttrValue::Static(""),
+            };
+            if key == "checked" {
+                checked = Some(true);
+            }
+
+            attributes.insert(key, (value, ApplyAttributeAs::Attribute));
+        }
+
+        Ok(Attrs {
+            attributes: Attributes::IndexMap(attributes),
+            listeners: Default::default(),
+            checked,
+        })
+    }
+
+    /// Render the attributes of this [`Attrs`] to HTML attribute syntax, e.g. `class="a" disabled="disabled"`.
+    ///
+    /// Attributes are rendered in insertion order and escaped like in Yew's `ServerRenderer`, so boolean attributes
+    /// are rendered with their name as value. Attributes applied as property and listeners are skipped, as well as
+    /// attributes with an invalid name, i.e. an empty name or one containing whitespace, control characters,
+    /// noncharacters or any of `"`, `'`, `<`, `>`, `/` and `=`.
+    ///
+    /// The checked state is rendered like Yew renders `input` elements, i.e. as `checked` without a value before the
+    /// other attributes, with [`Attrs::checked`] taking precedence over the `checked` attribute. Unlike this method,
+    /// Yew also renders the `value` of `input` elements before the other attributes.
+    pub fn to_html_string(&self) -> String {
+        let mut html = String::new();
+
+        if self.checked.unwrap_or_else(|| self.contains("checked")) {
+            html.push_str("checked");
+        }
+
+        for (name, value, apply_as) in self.iter() {
+            if apply_as == ApplyAttributeAs::Property
+                || name == "checked"
+                || !is_valid_attribute_name(name)
+            {
+                continue;
+            }
+
+            if !html.is_empty() {
+                html.push(' ');
+            }
+            html.push_str(name);
+            html.push_str("=\"");
+            html.push_str(&html_escape::encode_double_quoted_attribute(value));
+            html.push('"');
+        }
+
+        html
+    }
+}
+
+/// Whether a name is a valid HTML attribute name, i.e. it's not empty and doesn't contain whitespace, control
+/// characters, noncharacters or any of `"`, `'`, `<`, `>`, `/` and `=`.
+pub(crate) fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(is_name_char)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.input[self.position..];
+        let length = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        !self.take_while(|c| c.is_ascii_whitespace()).is_empty()
+    }
+
+    /// Parse the optional value of the attribute with the given name, including the `=`.
+    fn parse_value(&mut self, name: &str) -> Result<Option<&'a str>, AttrsError> {
+        let start = self.position;
+        self.skip_whitespace();
+        if self.peek() != Some('=') {
+            self.position = start;
+            return Ok(None);
+        }
+        self.position += 1;
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.position += 1;
+                let value = self.take_while(|c| c != quote);
+                if self.peek().is_none() {
+                    return Err(self.error(format!(
+                        "unterminated value of attribute `{name}`, expected `{quote}`"
+                    )));
+                }
+                self.position += 1;
+                Ok(Some(value))
+            }
+            Some(c) => {
+                let value = self.take_while(|c| !c.is_ascii_whitespace() && !"\"'=<>`".contains(c));
+                if value.is_empty() {
+                    return Err(
+                        self.error(format!("expected value of attribute `{name}`, found `{c}`"))
+                    );
+                }
+                match self.peek() {
+                    Some(c) if !c.is_ascii_whitespace() => Err(self.error(format!(
+                        "unexpected `{c}` in unquoted value of attribute `{name}`"
+                    ))),
+                    _ => Ok(Some(value)),
+                }
+            }
+            None => Err(self.error(format!(
+                "expected value of attribute `{name}`, found end of input"
+            ))),
+        }
+    }
+
+    fn error(&self, message: String) -> AttrsError {
+        AttrsError::Parse {
+            position: self.position,
+            message,
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    !c.is_whitespace()
+        && !c.is_control()
+        && !"\"'<>/=".contains(c)
+        && !matches!(c, '\u{fdd0}'..='\u{fdef}')
+        && (u32::from(c) & 0xfffe) != 0xfffe
+}
+
+fn is_event_handler(name: &str) -> bool {
+    name.get(..2)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+        && name.len() > 2
+        && name[2..].chars().all(|c| c.is_ascii_alphabetic())
+}
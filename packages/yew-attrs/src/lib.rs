@@ -41,7 +41,22 @@
 //! }
 //! ```
 
-pub use yew_attrs_macro::attrs;
+mod attributes;
+mod builder;
+mod html;
+pub mod listeners;
+pub mod merge;
+#[doc(hidden)]
+pub mod prefixed_map;
+mod select;
+#[cfg(feature = "serde")]
+mod serialize;
+mod style;
+mod take;
+
+pub use builder::AttrsBuilder;
+pub use take::TakeAttr;
+pub use yew_attrs_macro::{attrs, style};
 
 use indexmap::IndexMap;
 use thiserror::Error;
@@ -50,11 +65,39 @@ use yew::{
     AttrValue, Html, NodeRef,
 };
 
+use crate::{
+    merge::{merge_attributes, merge_listeners, MergeStrategies},
+    select::select_options,
+};
+
 /// Error for Attrs operations.
 #[derive(Debug, Error, PartialEq)]
 pub enum AttrsError {
+    /// Unsupported operation.
+    ///
+    /// Not returned by any operation of this version. It is reserved for operations which can't support every input, and kept so existing matches on [`AttrsError`] keep compiling.
     #[error("{0}")]
     Unsupported(String),
+    #[error("missing attribute `{0}`")]
+    Missing(String),
+    #[error("invalid value `{value}` for attribute `{name}`: {message}")]
+    Invalid {
+        name: String,
+        value: String,
+        message: String,
+    },
+    #[error("invalid HTML attributes at position {position}: {message}")]
+    Parse { position: usize, message: String },
+}
+
+/// Precedence when applying [`Attrs`] to an existing element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Precedence {
+    /// Attributes from the [`Attrs`] override attributes from the element.
+    #[default]
+    Attrs,
+    /// Attributes from the element override attributes from the [`Attrs`].
+    Element,
 }
 
 /// Container for dynamic attributes and listeners.
@@ -64,6 +107,11 @@ pub struct Attrs {
     pub attributes: Attributes,
     /// Dynamic listeners.
     pub listeners: Listeners,
+    /// Checked state of `input` elements.
+    ///
+    /// [`None`] falls back to the presence of the `checked` attribute, which leaves the checked state uncontrolled if absent.
+    /// When merging, a checked state or `checked` attribute of the other [`Attrs`] overrides this checked state.
+    pub checked: Option<bool>,
 }
 
 impl Attrs {
@@ -72,61 +120,101 @@ impl Attrs {
         Self {
             attributes,
             listeners,
+            checked: None,
         }
     }
 
+    /// Set the checked state of `input` elements, which allows explicitly unchecking them.
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
     /// Merge this [`Attrs`] and another [`Attrs`] into a new [`Attrs`].
     ///
-    /// Attributes from the other [`Attrs`] override attributes from this [`Attrs`]. Returns an error if merging is unsupported.
+    /// Attributes from the other [`Attrs`] override attributes from this [`Attrs`], except for `class`, which is combined and de-duplicated, and `style`, which is merged per CSS property.
+    ///
+    /// Merging never fails. The [`Result`] is kept for compatibility with earlier versions, which returned [`AttrsError::Unsupported`] for static and dynamic attributes. Use [`Attrs::merge_with`] to get the merged [`Attrs`] directly.
     pub fn merge(self, other: Attrs) -> Result<Attrs, AttrsError> {
-        Ok(Attrs::new(
-            merge_attributes(self.attributes, other.attributes)?,
-            merge_listeners(self.listeners, other.listeners),
-        ))
+        Ok(self.merge_with(other, &MergeStrategies::default()))
+    }
+
+    /// Merge this [`Attrs`] and another [`Attrs`] into a new [`Attrs`] using the given [`MergeStrategies`].
+    ///
+    /// Attributes present in both [`Attrs`] are merged with the strategy registered for their name. Static and dynamic attributes are converted to an index map first, omitting unset dynamic attributes.
+    pub fn merge_with(self, other: Attrs, strategies: &MergeStrategies) -> Attrs {
+        // A `checked` attribute of the other side also overrides the checked state of this side.
+        let checked = other
+            .checked
+            .or_else(|| other.contains("checked").then_some(true))
+            .or(self.checked);
+
+        Attrs {
+            attributes: merge_attributes(self.attributes, other.attributes, strategies),
+            listeners: merge_listeners(self.listeners, other.listeners, strategies.listeners()),
+            checked,
+        }
     }
 
     /// Create a new [`VTag`] using the attributes and listeners from this [`Attrs`].
+    ///
+    /// The tag is matched case-insensitively. `svg` and `math` are lowercased, so Yew creates them and their descendants in the SVG or MathML namespace. Other tags are kept as given, since SVG elements like `foreignObject` are case-sensitive.
+    ///
+    /// For `input` elements, [`Attrs::checked`] or the presence of the `checked` attribute controls the checked state. For `select` elements, the `value` attribute selects the `option` elements with a matching value, using whitespace-separated values if the `multiple` attribute is present. Option values containing whitespace can't be selected in a `select` element with the `multiple` attribute.
     pub fn new_vtag(self, tag: &str, node_ref: NodeRef, key: Option<Key>, children: Html) -> VTag {
-        match tag {
-            "input" | "INPUT" => {
-                let (value, checked) = {
-                    if let Attributes::IndexMap(map) = &self.attributes {
-                        (
-                            map.get("value").map(|(v, _)| v),
-                            map.get("checked").map(|(v, _)| v),
-                        )
-                    } else {
-                        (None, None)
-                    }
-                };
+        match tag.to_ascii_lowercase().as_str() {
+            "input" => {
+                let mut attributes = into_index_map(self.attributes);
+                let value = attributes.get("value").map(|(v, _)| v.clone());
+                let checked = attributes.shift_remove("checked").map(|_| true);
 
                 VTag::__new_input(
-                    value.cloned(),
-                    checked.map(|_| true),
+                    value,
+                    self.checked.or(checked),
                     node_ref,
                     key,
-                    self.attributes,
+                    Attributes::IndexMap(attributes),
                     self.listeners,
                 )
             }
-            "textarea" | "TEXTAREA" => {
-                let value = {
-                    if let Attributes::IndexMap(map) = &self.attributes {
-                        map.get("value").map(|(v, _)| v)
-                    } else {
-                        None
-                    }
-                };
+            "textarea" => {
+                let attributes = into_index_map(self.attributes);
+                let value = attributes.get("value").map(|(v, _)| v.clone());
 
                 VTag::__new_textarea(
-                    value.cloned(),
+                    value,
                     node_ref,
                     key,
-                    self.attributes,
+                    Attributes::IndexMap(attributes),
                     self.listeners,
                 )
             }
-            tag => VTag::__new_other(
+            "select" => {
+                let mut attributes = into_index_map(self.attributes);
+                let mut children = children;
+
+                if let Some((value, _)) = attributes.shift_remove("value") {
+                    select_options(&mut children, &value, attributes.contains_key("multiple"));
+                }
+
+                VTag::__new_other(
+                    tag.to_string().into(),
+                    node_ref,
+                    key,
+                    Attributes::IndexMap(attributes),
+                    self.listeners,
+                    children,
+                )
+            }
+            root @ ("svg" | "math") => VTag::__new_other(
+                root.to_string().into(),
+                node_ref,
+                key,
+                self.attributes,
+                self.listeners,
+                children,
+            ),
+            _ => VTag::__new_other(
                 tag.to_string().into(),
                 node_ref.clone(),
                 key,
@@ -136,6 +224,69 @@ impl Attrs {
             ),
         }
     }
+
+    /// Apply the attributes and listeners from this [`Attrs`] to an existing [`VTag`], e.g. one created with `html!`.
+    ///
+    /// Attributes present on both sides are merged like [`Attrs::merge`], with the [`Precedence`] deciding which side overrides the other. The `value` and `checked` attributes of `input` and `textarea` elements are applied as their value and checked state, and the `value` attribute of `select` elements selects options like [`Attrs::new_vtag`]. Listeners are added to the listeners of the [`VTag`].
+    pub fn apply_vtag(self, vtag: &mut VTag, precedence: Precedence) {
+        let mut attributes = into_index_map(self.attributes);
+        let overrides = |current: bool| precedence == Precedence::Attrs || !current;
+
+        let is_input = vtag.tag().eq_ignore_ascii_case("input");
+        if is_input || vtag.tag().eq_ignore_ascii_case("textarea") {
+            if let Some((value, _)) = attributes.shift_remove("value") {
+                if overrides(vtag.value().is_some()) {
+                    vtag.set_value(value);
+                }
+            }
+        }
+        if is_input {
+            let checked = attributes.shift_remove("checked").map(|_| true);
+
+            if let Some(checked) = self.checked.or(checked) {
+                if overrides(vtag.checked().is_some()) {
+                    vtag.set_checked(checked);
+                }
+            }
+        }
+        if vtag.tag().eq_ignore_ascii_case("select") {
+            if let Some((value, _)) = attributes.shift_remove("value") {
+                let multiple = attributes.contains_key("multiple")
+                    || vtag.attributes.iter().any(|(key, _)| key == "multiple");
+
+                if let Some(children) = vtag.children_mut() {
+                    select_options(children, &value, multiple);
+                }
+            }
+        }
+
+        let element = std::mem::take(&mut vtag.attributes);
+        let attributes = Attributes::IndexMap(attributes);
+        let strategies = MergeStrategies::default();
+        vtag.attributes = match precedence {
+            Precedence::Attrs => merge_attributes(element, attributes, &strategies),
+            Precedence::Element => merge_attributes(attributes, element, &strategies),
+        };
+
+        if let Listeners::Pending(listeners) = self.listeners {
+            for listener in listeners.into_vec().into_iter().flatten() {
+                vtag.add_listener(listener);
+            }
+        }
+    }
+
+    /// Apply the attributes and listeners from this [`Attrs`] to [`Html`] created with `html!`.
+    ///
+    /// See [`Attrs::apply_vtag`]. [`Html`] which is not an element is returned unchanged.
+    pub fn apply_html(self, html: Html, precedence: Precedence) -> Html {
+        match html {
+            Html::VTag(mut vtag) => {
+                self.apply_vtag(&mut vtag, precedence);
+                Html::VTag(vtag)
+            }
+            html => html,
+        }
+    }
 }
 
 impl Default for Attrs {
@@ -143,40 +294,14 @@ impl Default for Attrs {
         Self {
             attributes: Attributes::IndexMap(IndexMap::default()),
             listeners: Listeners::default(),
+            checked: None,
         }
     }
 }
 
-fn merge_attributes(a: Attributes, b: Attributes) -> Result<Attributes, AttrsError> {
-    match (a, b) {
-        (Attributes::IndexMap(a), Attributes::IndexMap(b)) => Ok(merge_index_map_attributes(a, b)),
-        _ => Err(AttrsError::Unsupported(
-            "Merging static or dynamic attributes is unsupported.".into(),
-        )),
-    }
-}
-
-fn merge_index_map_attributes(
-    a: IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
-    b: IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
-) -> Attributes {
-    let mut merged = IndexMap::new();
-    merged.extend(a);
-    merged.extend(b);
-
-    Attributes::IndexMap(merged)
-}
-
-fn merge_listeners(a: Listeners, b: Listeners) -> Listeners {
-    match (a, b) {
-        (Listeners::None, Listeners::None) => Listeners::None,
-        (Listeners::None, other) | (other, Listeners::None) => other,
-        (Listeners::Pending(a), Listeners::Pending(b)) => {
-            let mut merged = Vec::with_capacity(a.len() + b.len());
-            merged.extend(a);
-            merged.extend(b);
-
-            Listeners::Pending(merged.into_boxed_slice())
-        }
-    }
+/// Convert attributes of any representation into an index map, omitting unset dynamic attributes.
+pub(crate) fn into_index_map(
+    mut attributes: Attributes,
+) -> IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)> {
+    std::mem::take(attributes.get_mut_index_map())
 }
@@ -0,0 +1,266 @@
+//! Listener constructors for [`AttrsBuilder::listener`](crate::AttrsBuilder::listener) and listener management for
+//! [`Attrs`].
+//!
+//! # Example
+//! ```
+//! use yew::prelude::*;
+//! use yew_attrs::{listeners::onclick, Attrs};
+//!
+//! let attrs = Attrs::builder()
+//!     .listener(onclick(|_: MouseEvent| {}))
+//!     .build();
+//! ```
+//!
+//! Listeners for other events, e.g. custom events of web components, are created with [`on`] and [`on_passive`].
+
+use std::{borrow::Cow, rc::Rc};
+
+use yew::{
+    events::Event,
+    html::IntoEventCallback,
+    virtual_dom::{Listener, ListenerKind, Listeners},
+    Callback,
+};
+
+use crate::Attrs;
+
+macro_rules! listeners {
+    ($($name:ident)*) => {$(
+        #[doc = concat!("Create a `", stringify!($name), "` listener.")]
+        pub fn $name(
+            callback: impl IntoEventCallback<yew::html::$name::Event>,
+        ) -> Option<Rc<dyn Listener>> {
+            yew::html::$name::Wrapper::__macro_new(callback)
+        }
+    )*};
+}
+
+listeners! {
+    onabort
+    onauxclick
+    onblur
+    oncancel
+    oncanplay
+    oncanplaythrough
+    onchange
+    onclick
+    onclose
+    oncontextmenu
+    oncuechange
+    ondblclick
+    ondrag
+    ondragend
+    ondragenter
+    ondragexit
+    ondragleave
+    ondragover
+    ondragstart
+    ondrop
+    ondurationchange
+    onemptied
+    onended
+    onerror
+    onfocus
+    onfocusin
+    onfocusout
+    onformdata
+    oninput
+    oninvalid
+    onkeydown
+    onkeypress
+    onkeyup
+    onload
+    onloadeddata
+    onloadedmetadata
+    onloadstart
+    onmousedown
+    onmouseenter
+    onmouseleave
+    onmousemove
+    onmouseout
+    onmouseover
+    onmouseup
+    onpause
+    onplay
+    onplaying
+    onprogress
+    onratechange
+    onreset
+    onresize
+    onscroll
+    onsecuritypolicyviolation
+    onseeked
+    onseeking
+    onselect
+    onslotchange
+    onstalled
+    onsubmit
+    onsuspend
+    ontimeupdate
+    ontoggle
+    onvolumechange
+    onwaiting
+    onwheel
+    oncopy
+    oncut
+    onpaste
+    onanimationcancel
+    onanimationend
+    onanimationiteration
+    onanimationstart
+    ongotpointercapture
+    onloadend
+    onlostpointercapture
+    onpointercancel
+    onpointerdown
+    onpointerenter
+    onpointerleave
+    onpointerlockchange
+    onpointerlockerror
+    onpointermove
+    onpointerout
+    onpointerover
+    onpointerup
+    onselectionchange
+    onselectstart
+    onshow
+    ontouchcancel
+    ontouchend
+    ontouchmove
+    ontouchstart
+    ontransitioncancel
+    ontransitionend
+    ontransitionrun
+    ontransitionstart
+}
+
+/// Create a listener for the event with the given name, e.g. `sl-change`.
+///
+/// The callback receives an [`Event`], which can be cast into the actual event type, e.g. a `CustomEvent`.
+pub fn on(
+    event: impl Into<Cow<'static, str>>,
+    callback: impl IntoEventCallback<Event>,
+) -> Option<Rc<dyn Listener>> {
+    custom_listener(event.into(), callback, false)
+}
+
+/// Create a passive listener for the event with the given name, which can't prevent the default action.
+pub fn on_passive(
+    event: impl Into<Cow<'static, str>>,
+    callback: impl IntoEventCallback<Event>,
+) -> Option<Rc<dyn Listener>> {
+    custom_listener(event.into(), callback, true)
+}
+
+fn custom_listener(
+    event: Cow<'static, str>,
+    callback: impl IntoEventCallback<Event>,
+    passive: bool,
+) -> Option<Rc<dyn Listener>> {
+    callback.into_event_callback().map(|callback| {
+        Rc::new(CustomListener {
+            kind: ListenerKind::other(event),
+            callback,
+            passive,
+        }) as Rc<dyn Listener>
+    })
+}
+
+/// Listener for an event which is not one of Yew's built-in listeners.
+struct CustomListener {
+    kind: ListenerKind,
+    callback: Callback<Event>,
+    passive: bool,
+}
+
+impl Listener for CustomListener {
+    fn kind(&self) -> ListenerKind {
+        self.kind.clone()
+    }
+
+    fn handle(&self, event: Event) {
+        self.callback.emit(event);
+    }
+
+    fn passive(&self) -> bool {
+        self.passive
+    }
+}
+
+impl Attrs {
+    /// Iterate over all listeners in order.
+    pub fn iter_listeners(&self) -> impl Iterator<Item = &Rc<dyn Listener>> {
+        let listeners: &[Option<Rc<dyn Listener>>] = match &self.listeners {
+            Listeners::None => &[],
+            Listeners::Pending(listeners) => listeners,
+        };
+
+        listeners.iter().flatten()
+    }
+
+    /// Iterate over the listeners of a [`ListenerKind`] in order.
+    pub fn listeners_of<'a>(
+        &'a self,
+        kind: &'a ListenerKind,
+    ) -> impl Iterator<Item = &'a Rc<dyn Listener>> {
+        self.iter_listeners()
+            .filter(move |listener| listener.kind() == *kind)
+    }
+
+    /// Whether a listener of a [`ListenerKind`] is present.
+    pub fn has_listener(&self, kind: &ListenerKind) -> bool {
+        self.listeners_of(kind).next().is_some()
+    }
+
+    /// Add a listener after the existing listeners.
+    pub fn add_listener(&mut self, listener: Rc<dyn Listener>) {
+        let mut listeners = self.take_listeners();
+        listeners.push(Some(listener));
+        self.listeners = Listeners::Pending(listeners.into_boxed_slice());
+    }
+
+    /// Remove the listeners of a [`ListenerKind`], returning them in order.
+    pub fn remove_listeners(&mut self, kind: &ListenerKind) -> Vec<Rc<dyn Listener>> {
+        self.replace_listeners(kind, None)
+    }
+
+    /// Replace the listeners of a [`ListenerKind`] with a single listener at the position of the first one, returning
+    /// the replaced listeners in order.
+    ///
+    /// This allows wrapping forwarded listeners, e.g. to intercept events. If no listener of the kind is present, the
+    /// listener is added after the existing listeners.
+    pub fn replace_listeners(
+        &mut self,
+        kind: &ListenerKind,
+        listener: Option<Rc<dyn Listener>>,
+    ) -> Vec<Rc<dyn Listener>> {
+        let mut replacement = listener;
+        let mut removed = vec![];
+        let mut listeners = vec![];
+
+        for existing in self.take_listeners().into_iter().flatten() {
+            if existing.kind() == *kind {
+                removed.push(existing);
+                listeners.extend(replacement.take().map(Some));
+            } else {
+                listeners.push(Some(existing));
+            }
+        }
+        listeners.extend(replacement.map(Some));
+
+        self.listeners = if listeners.is_empty() {
+            Listeners::None
+        } else {
+            Listeners::Pending(listeners.into_boxed_slice())
+        };
+
+        removed
+    }
+
+    fn take_listeners(&mut self) -> Vec<Option<Rc<dyn Listener>>> {
+        match std::mem::take(&mut self.listeners) {
+            Listeners::None => vec![],
+            Listeners::Pending(listeners) => listeners.into_vec(),
+        }
+    }
+}
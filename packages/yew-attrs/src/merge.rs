@@ -0,0 +1,272 @@
+//! Strategies for merging attributes.
+
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+use yew::{
+    events::Event,
+    html::{Classes, IntoPropValue},
+    virtual_dom::{ApplyAttributeAs, Attributes, Listener, ListenerKind, Listeners},
+    AttrValue,
+};
+
+use crate::{into_index_map, style::merge_styles};
+
+/// Strategy for merging two values of the same attribute.
+pub trait MergeStrategy {
+    /// Merge value `a` from the left [`Attrs`](crate::Attrs) and value `b` from the right [`Attrs`](crate::Attrs).
+    fn merge(&self, a: AttrValue, b: AttrValue) -> AttrValue;
+}
+
+impl<F: Fn(AttrValue, AttrValue) -> AttrValue> MergeStrategy for F {
+    fn merge(&self, a: AttrValue, b: AttrValue) -> AttrValue {
+        self(a, b)
+    }
+}
+
+/// Use the value from the right side.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Override;
+
+impl MergeStrategy for Override {
+    fn merge(&self, _a: AttrValue, b: AttrValue) -> AttrValue {
+        b
+    }
+}
+
+/// Keep the value from the left side.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeepLeft;
+
+impl MergeStrategy for KeepLeft {
+    fn merge(&self, a: AttrValue, _b: AttrValue) -> AttrValue {
+        a
+    }
+}
+
+/// Concatenate both values with a separator.
+#[derive(Clone, Debug)]
+pub struct Concat(pub AttrValue);
+
+impl Concat {
+    /// Create a new [`Concat`] with the given separator.
+    pub fn new(separator: impl Into<AttrValue>) -> Self {
+        Self(separator.into())
+    }
+}
+
+impl MergeStrategy for Concat {
+    fn merge(&self, a: AttrValue, b: AttrValue) -> AttrValue {
+        match (a.is_empty(), b.is_empty()) {
+            (true, _) => b,
+            (_, true) => a,
+            _ => format!("{}{}{}", a, self.0, b).into(),
+        }
+    }
+}
+
+/// Union of whitespace-separated tokens, preserving order and removing duplicates.
+///
+/// Used for `class` by default, and suited for attributes such as `rel` or `aria-describedby`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenList;
+
+impl MergeStrategy for TokenList {
+    fn merge(&self, a: AttrValue, b: AttrValue) -> AttrValue {
+        let mut tokens = Classes::from(a);
+        tokens.push(b);
+
+        IntoPropValue::<AttrValue>::into_prop_value(tokens)
+    }
+}
+
+/// Merge inline CSS declarations, overriding individual properties.
+///
+/// Used for `style` by default. A declaration from the right side overrides a declaration for the same property from
+/// the left side, unless only the left declaration is `!important`. Malformed declarations are dropped.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Style;
+
+impl MergeStrategy for Style {
+    fn merge(&self, a: AttrValue, b: AttrValue) -> AttrValue {
+        merge_styles(&a, &b)
+    }
+}
+
+/// Strategy for merging listeners.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ListenerStrategy {
+    /// Keep all listeners, registering listeners of the same kind separately.
+    #[default]
+    Append,
+    /// Compose listeners of the same kind into a single listener, running listeners from the left side first.
+    ///
+    /// Remaining listeners are skipped once a listener prevents the default action of the event.
+    ComposeLeftFirst,
+    /// Compose listeners of the same kind into a single listener, running listeners from the right side first.
+    ///
+    /// Remaining listeners are skipped once a listener prevents the default action of the event.
+    ComposeRightFirst,
+}
+
+/// Merge strategies per attribute name.
+///
+/// Attributes without a registered strategy use [`TokenList`] for `class`, [`Style`] for `style` and [`Override`]
+/// for everything else. Listeners use [`ListenerStrategy::Append`] by default.
+#[derive(Clone, Default)]
+pub struct MergeStrategies {
+    strategies: IndexMap<AttrValue, Rc<dyn MergeStrategy>>,
+    listeners: ListenerStrategy,
+}
+
+impl MergeStrategies {
+    /// Create new [`MergeStrategies`] with the default strategies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a strategy for an attribute, replacing any previously registered strategy.
+    pub fn insert(
+        &mut self,
+        name: impl Into<AttrValue>,
+        strategy: impl MergeStrategy + 'static,
+    ) -> &mut Self {
+        self.strategies.insert(name.into(), Rc::new(strategy));
+        self
+    }
+
+    /// Register a strategy for an attribute, replacing any previously registered strategy.
+    pub fn with(
+        mut self,
+        name: impl Into<AttrValue>,
+        strategy: impl MergeStrategy + 'static,
+    ) -> Self {
+        self.insert(name, strategy);
+        self
+    }
+
+    /// Set the strategy for listeners.
+    pub fn with_listeners(mut self, strategy: ListenerStrategy) -> Self {
+        self.listeners = strategy;
+        self
+    }
+
+    /// Get the strategy for listeners.
+    pub fn listeners(&self) -> ListenerStrategy {
+        self.listeners
+    }
+
+    /// Get the strategy for an attribute.
+    pub fn get(&self, name: &str) -> &dyn MergeStrategy {
+        match self.strategies.get(name) {
+            Some(strategy) => strategy.as_ref(),
+            None => match name {
+                "class" => &TokenList,
+                "style" => &Style,
+                _ => &Override,
+            },
+        }
+    }
+}
+
+pub(crate) fn merge_attributes(
+    a: Attributes,
+    b: Attributes,
+    strategies: &MergeStrategies,
+) -> Attributes {
+    merge_index_map_attributes(into_index_map(a), into_index_map(b), strategies)
+}
+
+fn merge_index_map_attributes(
+    a: IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+    b: IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+    strategies: &MergeStrategies,
+) -> Attributes {
+    let mut merged = a;
+
+    for (key, (value, apply_as)) in b {
+        match merged.get_mut(&key) {
+            Some(existing) => {
+                let existing_value = std::mem::take(&mut existing.0);
+                *existing = (strategies.get(&key).merge(existing_value, value), apply_as);
+            }
+            None => {
+                merged.insert(key, (value, apply_as));
+            }
+        }
+    }
+
+    Attributes::IndexMap(merged)
+}
+
+pub(crate) fn merge_listeners(a: Listeners, b: Listeners, strategy: ListenerStrategy) -> Listeners {
+    match (a, b) {
+        (Listeners::None, Listeners::None) => Listeners::None,
+        (Listeners::None, other) | (other, Listeners::None) => other,
+        (Listeners::Pending(a), Listeners::Pending(b)) => match strategy {
+            ListenerStrategy::Append => {
+                let mut merged = Vec::with_capacity(a.len() + b.len());
+                merged.extend(a);
+                merged.extend(b);
+
+                Listeners::Pending(merged.into_boxed_slice())
+            }
+            ListenerStrategy::ComposeLeftFirst => compose_listeners(a, b),
+            ListenerStrategy::ComposeRightFirst => compose_listeners(b, a),
+        },
+    }
+}
+
+fn compose_listeners(
+    first: Box<[Option<Rc<dyn Listener>>]>,
+    second: Box<[Option<Rc<dyn Listener>>]>,
+) -> Listeners {
+    let mut groups: IndexMap<ListenerKind, Vec<Rc<dyn Listener>>> = IndexMap::new();
+    for listener in first
+        .into_vec()
+        .into_iter()
+        .chain(second.into_vec())
+        .flatten()
+    {
+        groups.entry(listener.kind()).or_default().push(listener);
+    }
+
+    Listeners::Pending(
+        groups
+            .into_iter()
+            .map(|(kind, mut listeners)| {
+                Some(if listeners.len() == 1 {
+                    listeners.remove(0)
+                } else {
+                    Rc::new(ComposedListener { kind, listeners }) as Rc<dyn Listener>
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Listener which runs multiple listeners of the same kind in order.
+struct ComposedListener {
+    kind: ListenerKind,
+    listeners: Vec<Rc<dyn Listener>>,
+}
+
+impl Listener for ComposedListener {
+    fn kind(&self) -> ListenerKind {
+        self.kind.clone()
+    }
+
+    fn handle(&self, event: Event) {
+        for (index, listener) in self.listeners.iter().enumerate() {
+            if index > 0 && event.default_prevented() {
+                break;
+            }
+
+            listener.handle(event.clone());
+        }
+    }
+
+    fn passive(&self) -> bool {
+        self.listeners.iter().all(|listener| listener.passive())
+    }
+}
@@ -0,0 +1,82 @@
+//! Support for the `data={...}` and `aria={...}` shorthands of the `attrs!` macro.
+//!
+//! The value of `data={...}` is inserted as the `data` attribute if it converts into an attribute value, e.g. the URL of
+//! an `object` element, and as prefixed attributes otherwise. The choice is made by type, using autoref-based
+//! specialization: [`InsertAttribute`] is implemented for `&PrefixedValue<T>` and takes precedence over
+//! [`InsertEntries`], which is implemented for `PrefixedValue<T>`, when called as `(&&value).insert(...)`.
+
+use std::{cell::Cell, fmt::Display};
+
+use indexmap::IndexMap;
+use yew::{html::IntoPropValue, virtual_dom::ApplyAttributeAs, AttrValue};
+
+/// Value of a `data` or `aria` shorthand.
+pub struct PrefixedValue<T>(Cell<Option<T>>);
+
+impl<T> PrefixedValue<T> {
+    pub fn new(value: T) -> Self {
+        Self(Cell::new(Some(value)))
+    }
+
+    fn take(&self) -> T {
+        self.0
+            .take()
+            .expect("value of shorthand should be inserted once")
+    }
+}
+
+/// Insert the value as attribute with the name of the shorthand, omitting a [`None`] value.
+pub trait InsertAttribute {
+    fn insert(
+        &self,
+        map: &mut IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+        name: &'static str,
+    );
+}
+
+impl<T: IntoPropValue<Option<AttrValue>>> InsertAttribute for &PrefixedValue<T> {
+    fn insert(
+        &self,
+        map: &mut IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+        name: &'static str,
+    ) {
+        if let Some(value) = self.take().into_prop_value() {
+            map.insert(
+                AttrValue::Static(name),
+                (value, ApplyAttributeAs::Attribute),
+            );
+        }
+    }
+}
+
+/// Insert the entries of the value as attributes prefixed with the name of the shorthand, omitting entries with a
+/// [`None`] value.
+pub trait InsertEntries {
+    fn insert(
+        &self,
+        map: &mut IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+        name: &'static str,
+    );
+}
+
+impl<T, K, V> InsertEntries for PrefixedValue<T>
+where
+    T: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: IntoPropValue<Option<AttrValue>>,
+{
+    fn insert(
+        &self,
+        map: &mut IndexMap<AttrValue, (AttrValue, ApplyAttributeAs)>,
+        name: &'static str,
+    ) {
+        for (key, value) in self.take() {
+            if let Some(value) = value.into_prop_value() {
+                map.insert(
+                    AttrValue::from(format!("{name}-{key}")),
+                    (value, ApplyAttributeAs::Attribute),
+                );
+            }
+        }
+    }
+}
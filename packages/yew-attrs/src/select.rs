@@ -0,0 +1,82 @@
+//! Selected options of `select` elements.
+
+use yew::{
+    virtual_dom::{ApplyAttributeAs, VNode, VTag},
+    AttrValue,
+};
+
+/// Mark the `option` elements in the children of a `select` element as selected if their value matches.
+///
+/// A `select` element with the `multiple` attribute uses whitespace-separated values, so option values containing
+/// whitespace can't be selected this way. Otherwise only the first matching option is selected. Other options are
+/// unselected. Options rendered by components are not visited.
+///
+/// The options are selected with the `selected` property instead of setting the `value` property of the `select`
+/// element, because Yew applies the properties of an element before rendering its children. The selected options
+/// determine `select.value` and `select.selectedOptions` on mount and on every update.
+pub(crate) fn select_options(children: &mut VNode, value: &str, multiple: bool) {
+    let values: Vec<&str> = if multiple {
+        value.split_whitespace().collect()
+    } else {
+        vec![value]
+    };
+
+    let mut found = false;
+    visit(children, &values, multiple, &mut found);
+}
+
+fn visit(node: &mut VNode, values: &[&str], multiple: bool, found: &mut bool) {
+    match node {
+        VNode::VList(list) => {
+            for child in list.iter_mut() {
+                visit(child, values, multiple, found);
+            }
+        }
+        VNode::VTag(tag) if tag.tag().eq_ignore_ascii_case("optgroup") => {
+            if let Some(children) = tag.children_mut() {
+                visit(children, values, multiple, found);
+            }
+        }
+        VNode::VTag(tag) if tag.tag().eq_ignore_ascii_case("option") => {
+            let selected = (multiple || !*found) && values.contains(&option_value(tag).as_str());
+            *found |= selected;
+
+            let attributes = tag.attributes.get_mut_index_map();
+            if selected {
+                attributes.insert(
+                    AttrValue::Static("selected"),
+                    (AttrValue::Static("selected"), ApplyAttributeAs::Property),
+                );
+            } else {
+                attributes.shift_remove("selected");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Value of an `option` element, which defaults to its text with whitespace stripped and collapsed.
+fn option_value(tag: &VTag) -> String {
+    if let Some((_, value)) = tag.attributes.iter().find(|(key, _)| *key == "value") {
+        return value.to_string();
+    }
+
+    let mut text = String::new();
+    if let Some(children) = tag.children() {
+        collect_text(children, &mut text);
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn collect_text(node: &VNode, text: &mut String) {
+    match node {
+        VNode::VText(vtext) => text.push_str(&vtext.text),
+        VNode::VList(list) => {
+            for child in list.iter() {
+                collect_text(child, text);
+            }
+        }
+        _ => {}
+    }
+}
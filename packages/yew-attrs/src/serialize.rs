@@ -0,0 +1,190 @@
+//! Serde support for the attributes of [`Attrs`].
+//!
+//! Attributes are represented as a map from name to value. Attributes applied as property are represented as a map
+//! with `value` and `apply_as` (`"attribute"` or `"property"`). The checked state is represented as a boolean `checked`
+//! entry, which replaces the `checked` attribute. Listeners are skipped.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use yew::{
+    virtual_dom::{ApplyAttributeAs, Attributes},
+    AttrValue,
+};
+
+use crate::{html::is_valid_attribute_name, Attrs};
+
+impl Serialize for Attrs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = match self.checked {
+            Some(_) if self.contains("checked") => self.len(),
+            Some(_) => self.len() + 1,
+            None => self.len(),
+        };
+
+        let mut map = serializer.serialize_map(Some(len))?;
+        for (name, value, apply_as) in self.iter() {
+            if self.checked.is_some() && name == "checked" {
+                continue;
+            }
+
+            match apply_as {
+                ApplyAttributeAs::Attribute => map.serialize_entry(name, value)?,
+                ApplyAttributeAs::Property => map.serialize_entry(name, &Property(value))?,
+            }
+        }
+        if let Some(checked) = self.checked {
+            map.serialize_entry("checked", &checked)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Attrs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(AttrsVisitor)
+    }
+}
+
+struct AttrsVisitor;
+
+impl<'de> Visitor<'de> for AttrsVisitor {
+    type Value = Attrs;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of attribute names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut attributes = IndexMap::with_capacity(access.size_hint().unwrap_or_default());
+        let mut checked = None;
+
+        while let Some(name) = access.next_key::<String>()? {
+            if !is_valid_attribute_name(&name) {
+                return Err(de::Error::custom(format_args!(
+                    "invalid attribute name `{name}`"
+                )));
+            }
+
+            let entry = access.next_value::<Entry>()?;
+            if attributes.contains_key(name.as_str()) || (name == "checked" && checked.is_some()) {
+                return Err(de::Error::custom(format_args!(
+                    "duplicate attribute `{name}`"
+                )));
+            }
+
+            match entry {
+                Entry::Attribute(value, apply_as) => {
+                    attributes.insert(AttrValue::from(name), (value, apply_as));
+                }
+                Entry::Checked(value) if name == "checked" => {
+                    checked = Some(value);
+                    if value {
+                        attributes.insert(
+                            AttrValue::from(name),
+                            (AttrValue::Static("checked"), ApplyAttributeAs::Attribute),
+                        );
+                    }
+                }
+                Entry::Checked(value) => {
+                    return Err(de::Error::custom(format_args!(
+                        "boolean value `{value}` of attribute `{name}`, only `checked` can be a boolean"
+                    )));
+                }
+            }
+        }
+
+        Ok(Attrs {
+            attributes: Attributes::IndexMap(attributes),
+            listeners: Default::default(),
+            checked,
+        })
+    }
+}
+
+/// Value of an attribute applied as property.
+struct Property<'a>(&'a str);
+
+impl Serialize for Property<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("value", self.0)?;
+        map.serialize_entry("apply_as", "property")?;
+        map.end()
+    }
+}
+
+/// Value of an attribute and whether it is applied as attribute or property, or the checked state.
+enum Entry {
+    Attribute(AttrValue, ApplyAttributeAs),
+    Checked(bool),
+}
+
+impl<'de> Deserialize<'de> for Entry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(EntryVisitor)
+    }
+}
+
+struct EntryVisitor;
+
+impl<'de> Visitor<'de> for EntryVisitor {
+    type Value = Entry;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an attribute value string or a map with `value` and `apply_as`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Entry::Attribute(
+            AttrValue::from(value.to_string()),
+            ApplyAttributeAs::Attribute,
+        ))
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Entry::Checked(value))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        let mut apply_as = None;
+
+        while let Some(key) = access.next_key::<String>()? {
+            match key.as_str() {
+                "value" => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(access.next_value::<String>()?);
+                }
+                "apply_as" => {
+                    if apply_as.is_some() {
+                        return Err(de::Error::duplicate_field("apply_as"));
+                    }
+                    apply_as = Some(match access.next_value::<String>()?.as_str() {
+                        "attribute" => ApplyAttributeAs::Attribute,
+                        "property" => ApplyAttributeAs::Property,
+                        other => {
+                            return Err(de::Error::unknown_variant(
+                                other,
+                                &["attribute", "property"],
+                            ))
+                        }
+                    });
+                }
+                other => return Err(de::Error::unknown_field(other, &["value", "apply_as"])),
+            }
+        }
+
+        Ok(Entry::Attribute(
+            AttrValue::from(value.ok_or_else(|| de::Error::missing_field("value"))?),
+            apply_as.unwrap_or(ApplyAttributeAs::Attribute),
+        ))
+    }
+}
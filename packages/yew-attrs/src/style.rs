@@ -0,0 +1,130 @@
+//! Inline `style` declarations.
+
+use indexmap::IndexMap;
+use yew::AttrValue;
+
+/// A single CSS declaration from an inline `style` attribute.
+struct Declaration<'a> {
+    property: &'a str,
+    value: &'a str,
+    important: bool,
+}
+
+impl<'a> Declaration<'a> {
+    fn parse(declaration: &'a str) -> Option<Self> {
+        let (property, value) = declaration.split_once(':')?;
+        let property = property.trim();
+        let (value, important) = strip_important(value.trim());
+
+        if property.is_empty() || property.contains(char::is_whitespace) || value.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            property,
+            value,
+            important,
+        })
+    }
+
+    /// Key used to compare properties. Custom properties are case-sensitive, other properties are not.
+    fn key(&self) -> String {
+        if self.property.starts_with("--") {
+            self.property.to_string()
+        } else {
+            self.property.to_ascii_lowercase()
+        }
+    }
+}
+
+/// Merge two inline styles into a new inline style.
+///
+/// Declarations from `b` override declarations for the same property from `a`, unless the declaration from `a` is
+/// `!important` and the declaration from `b` is not, matching the result of concatenating both styles. Malformed
+/// declarations are dropped.
+pub(crate) fn merge_styles(a: &str, b: &str) -> AttrValue {
+    let mut declarations: IndexMap<String, Declaration> = IndexMap::new();
+
+    for declaration in parse(a).chain(parse(b)) {
+        let key = declaration.key();
+
+        match declarations.get_mut(&key) {
+            Some(existing) if existing.important && !declaration.important => {}
+            Some(existing) => *existing = declaration,
+            None => {
+                declarations.insert(key, declaration);
+            }
+        }
+    }
+
+    declarations
+        .values()
+        .map(|declaration| {
+            format!(
+                "{}: {}{}",
+                declaration.property,
+                declaration.value,
+                if declaration.important {
+                    " !important"
+                } else {
+                    ""
+                }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+        .into()
+}
+
+fn parse(style: &str) -> impl Iterator<Item = Declaration<'_>> {
+    split_declarations(style)
+        .into_iter()
+        .filter_map(Declaration::parse)
+}
+
+/// Split a style on `;`, ignoring semicolons in strings, parentheses and escapes.
+fn split_declarations(style: &str) -> Vec<&str> {
+    let mut declarations = vec![];
+    let mut start = 0;
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+
+    for (index, c) in style.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match (c, quote) {
+            ('\\', _) => escaped = true,
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(c),
+            ('(', None) => depth += 1,
+            (')', None) => depth = depth.saturating_sub(1),
+            (';', None) if depth == 0 => {
+                declarations.push(&style[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&style[start..]);
+
+    declarations
+}
+
+fn strip_important(value: &str) -> (&str, bool) {
+    let lowercase = value.to_ascii_lowercase();
+
+    if let Some(rest) = lowercase
+        .strip_suffix("important")
+        .map(str::trim_end)
+        .and_then(|rest| rest.strip_suffix('!'))
+    {
+        (value[..rest.len()].trim_end(), true)
+    } else {
+        (value, false)
+    }
+}
@@ -0,0 +1,182 @@
+//! Taking attributes and listeners out of [`Attrs`].
+
+use std::{borrow::Cow, rc::Rc};
+
+use indexmap::IndexMap;
+use yew::{
+    events::Event,
+    html::Classes,
+    virtual_dom::{Attributes, Listener, ListenerKind, Listeners},
+    AttrValue, Callback,
+};
+
+use crate::{into_index_map, Attrs, AttrsError};
+
+/// Type which can be taken out of [`Attrs`] by name with [`Attrs::take`].
+///
+/// An attribute which can't be parsed is kept in the [`Attrs`].
+pub trait TakeAttr: Sized {
+    /// Take the attribute or listeners with the given name out of the [`Attrs`].
+    fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError>;
+}
+
+/// Boolean attribute, which is `true` if present. For `checked`, [`Attrs::checked`] takes precedence.
+impl TakeAttr for bool {
+    fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+        let present = attrs.remove(name).is_some();
+
+        Ok(match name {
+            "checked" => attrs.checked.take().unwrap_or(present),
+            _ => present,
+        })
+    }
+}
+
+macro_rules! impl_take_attr_from_str {
+    ($($ty:ty)*) => {$(
+        impl TakeAttr for $ty {
+            fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+                let value = attrs
+                    .get(name)
+                    .ok_or_else(|| AttrsError::Missing(name.to_string()))?;
+
+                // Only remove the attribute once it is parsed, so it is kept on error.
+                let parsed = value.parse().map_err(|error| AttrsError::Invalid {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    message: format!("{error}"),
+                })?;
+                attrs.remove(name);
+
+                Ok(parsed)
+            }
+        }
+
+        impl TakeAttr for Option<$ty> {
+            fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+                if attrs.contains(name) {
+                    <$ty>::take_from(attrs, name).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    )*};
+}
+
+impl_take_attr_from_str! {
+    AttrValue String char
+    i8 i16 i32 i64 i128 isize
+    u8 u16 u32 u64 u128 usize
+    f32 f64
+}
+
+/// Classes, which are empty if absent.
+impl TakeAttr for Classes {
+    fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+        Ok(attrs.remove(name).map(Classes::from).unwrap_or_default())
+    }
+}
+
+/// Listeners with the given name (e.g. `onclick` or `on:sl-change`), which are empty if absent.
+impl TakeAttr for Vec<Rc<dyn Listener>> {
+    fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+        let (taken, rest) = split_listeners(std::mem::take(&mut attrs.listeners), &[name]);
+        attrs.listeners = rest;
+
+        Ok(match taken {
+            Listeners::None => vec![],
+            Listeners::Pending(listeners) => listeners.into_vec().into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Callback running the listeners with the given name (e.g. `onclick` or `on:sl-change`) in order, which is [`None`] if
+/// absent.
+impl TakeAttr for Option<Callback<Event>> {
+    fn take_from(attrs: &mut Attrs, name: &str) -> Result<Self, AttrsError> {
+        let listeners = Vec::<Rc<dyn Listener>>::take_from(attrs, name)?;
+
+        Ok((!listeners.is_empty()).then(|| {
+            Callback::from(move |event: Event| {
+                for listener in &listeners {
+                    listener.handle(event.clone());
+                }
+            })
+        }))
+    }
+}
+
+impl Attrs {
+    /// Split this [`Attrs`] into the attributes and listeners with the given names and the remaining ones.
+    ///
+    /// Listeners are matched by their name, e.g. `onclick` or `on:sl-change` for listeners of other events.
+    pub fn split(self, names: &[&str]) -> (Attrs, Attrs) {
+        let mut taken = IndexMap::new();
+        let mut rest = IndexMap::new();
+        for (key, value) in into_index_map(self.attributes) {
+            if names.contains(&key.as_str()) {
+                taken.insert(key, value);
+            } else {
+                rest.insert(key, value);
+            }
+        }
+
+        let (taken_listeners, rest_listeners) = split_listeners(self.listeners, names);
+        let (taken_checked, rest_checked) = if names.contains(&"checked") {
+            (self.checked, None)
+        } else {
+            (None, self.checked)
+        };
+
+        (
+            Attrs {
+                attributes: Attributes::IndexMap(taken),
+                listeners: taken_listeners,
+                checked: taken_checked,
+            },
+            Attrs {
+                attributes: Attributes::IndexMap(rest),
+                listeners: rest_listeners,
+                checked: rest_checked,
+            },
+        )
+    }
+
+    /// Take an attribute or the listeners with the given name out of this [`Attrs`], parsed into a Rust type.
+    ///
+    /// See the implementations of [`TakeAttr`] for the supported types.
+    pub fn take<T: TakeAttr>(&mut self, name: &str) -> Result<T, AttrsError> {
+        T::take_from(self, name)
+    }
+}
+
+fn split_listeners(listeners: Listeners, names: &[&str]) -> (Listeners, Listeners) {
+    let Listeners::Pending(listeners) = listeners else {
+        return (Listeners::None, Listeners::None);
+    };
+
+    let (taken, rest): (Vec<_>, Vec<_>) = listeners
+        .into_vec()
+        .into_iter()
+        .flatten()
+        .partition(|listener| names.contains(&listener_name(&listener.kind()).as_ref()));
+
+    let into_listeners = |listeners: Vec<Rc<dyn Listener>>| {
+        if listeners.is_empty() {
+            Listeners::None
+        } else {
+            Listeners::Pending(listeners.into_iter().map(Some).collect())
+        }
+    };
+
+    (into_listeners(taken), into_listeners(rest))
+}
+
+/// Name of a listener as written in [`attrs!`](crate::attrs), i.e. `onclick` or `on:sl-change` for other events.
+fn listener_name(kind: &ListenerKind) -> Cow<'_, str> {
+    match kind {
+        ListenerKind::other(event) => Cow::Owned(format!("on:{event}")),
+        kind => Cow::Borrowed(kind.as_ref()),
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use yew::{prelude::*, ServerRenderer};
+use yew_attrs::{attrs, Attrs, Precedence};
+
+#[derive(PartialEq, Properties)]
+struct ButtonProps {
+    pub precedence: Precedence,
+    #[prop_or_default]
+    pub attrs: Attrs,
+}
+
+#[function_component]
+fn Button(props: &ButtonProps) -> Html {
+    props.attrs.clone().apply_html(
+        html! {
+            <button class="btn" type="button" style="color: red; width: 1px">{"Click"}</button>
+        },
+        props.precedence,
+    )
+}
+
+#[derive(PartialEq, Properties)]
+struct InputProps {
+    pub precedence: Precedence,
+    #[prop_or_default]
+    pub attrs: Attrs,
+}
+
+#[function_component]
+fn Input(props: &InputProps) -> Html {
+    props
+        .attrs
+        .clone()
+        .apply_html(html! { <input value="a" /> }, props.precedence)
+}
+
+async fn render<C: BaseComponent<Properties = ()>>() -> String {
+    ServerRenderer::<C>::new().hydratable(false).render().await
+}
+
+#[function_component]
+fn AttrsPrecedenceApp() -> Html {
+    html! {
+        <Button
+            precedence={Precedence::Attrs}
+            attrs={attrs! {type="submit" class="text-red" style="color: blue" disabled=true}}
+        />
+    }
+}
+
+#[function_component]
+fn ElementPrecedenceApp() -> Html {
+    html! {
+        <Button
+            precedence={Precedence::Element}
+            attrs={attrs! {type="submit" class="text-red" style="color: blue" disabled=true}}
+        />
+    }
+}
+
+#[function_component]
+fn InputApp() -> Html {
+    html! {
+        <>
+            <Input precedence={Precedence::Attrs} attrs={attrs! {value="b"}} />
+            <Input precedence={Precedence::Element} attrs={attrs! {value="b"}} />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn apply_attrs_precedence() {
+    assert_eq!(
+        "<button type=\"submit\" style=\"color: blue; width: 1px\" class=\"btn text-red\" disabled=\"disabled\">Click</button>",
+        render::<AttrsPrecedenceApp>().await
+    );
+}
+
+#[tokio::test]
+async fn apply_element_precedence() {
+    assert_eq!(
+        "<button type=\"button\" style=\"color: red; width: 1px\" disabled=\"disabled\" class=\"text-red btn\">Click</button>",
+        render::<ElementPrecedenceApp>().await
+    );
+}
+
+#[tokio::test]
+async fn apply_input_value() {
+    assert_eq!(
+        "<input value=\"b\"><input value=\"a\">",
+        render::<InputApp>().await
+    );
+}
+
+#[function_component]
+fn SelectApp() -> Html {
+    attrs! {value="b"}.apply_html(
+        html! {
+            <select>
+                <option value="a">{"A"}</option>
+                <option value="b">{"B"}</option>
+            </select>
+        },
+        Precedence::Attrs,
+    )
+}
+
+#[tokio::test]
+async fn apply_select_value() {
+    assert_eq!(
+        "<select><option value=\"a\">A</option><option value=\"b\" selected=\"selected\">B</option></select>",
+        render::<SelectApp>().await
+    );
+}
@@ -0,0 +1,95 @@
+use yew::{
+    virtual_dom::{ApplyAttributeAs, Attributes, Listeners},
+    AttrValue,
+};
+use yew_attrs::{attrs, Attrs};
+
+fn attrs_static() -> Attrs {
+    Attrs::new(
+        Attributes::Static(&[
+            ("id", "a", ApplyAttributeAs::Attribute),
+            ("value", "b", ApplyAttributeAs::Property),
+        ]),
+        Listeners::None,
+    )
+}
+
+fn attrs_dynamic() -> Attrs {
+    Attrs::new(
+        Attributes::Dynamic {
+            keys: &["id", "hidden", "value"],
+            values: Box::new([
+                Some((AttrValue::Static("a"), ApplyAttributeAs::Attribute)),
+                None,
+                Some((AttrValue::Static("b"), ApplyAttributeAs::Property)),
+            ]),
+        },
+        Listeners::None,
+    )
+}
+
+fn attrs_index_map() -> Attrs {
+    attrs! { id="a" ~value="b" }
+}
+
+#[test]
+fn attributes_read() {
+    for attrs in [attrs_static(), attrs_dynamic(), attrs_index_map()] {
+        assert_eq!(Some("a"), attrs.get("id"));
+        assert_eq!(
+            Some(("b", ApplyAttributeAs::Property)),
+            attrs.get_with_apply_as("value")
+        );
+        assert_eq!(None, attrs.get("hidden"));
+        assert!(attrs.contains("id"));
+        assert!(!attrs.contains("hidden"));
+        assert_eq!(2, attrs.len());
+        assert!(!attrs.is_empty());
+        assert_eq!(
+            vec![
+                ("id", "a", ApplyAttributeAs::Attribute),
+                ("value", "b", ApplyAttributeAs::Property)
+            ],
+            attrs.iter().collect::<Vec<_>>()
+        );
+    }
+
+    assert!(Attrs::default().is_empty());
+}
+
+#[test]
+fn attributes_write() {
+    for mut attrs in [attrs_static(), attrs_dynamic(), attrs_index_map()] {
+        assert_eq!(None, attrs.insert("title", "c"));
+        assert_eq!(
+            Some(AttrValue::Static("a")),
+            attrs.insert_as("id", "d", ApplyAttributeAs::Property)
+        );
+        assert_eq!(None, attrs.remove("hidden"));
+        assert_eq!(Some(AttrValue::Static("b")), attrs.remove("value"));
+
+        assert_eq!(
+            vec![
+                ("id", "d", ApplyAttributeAs::Property),
+                ("title", "c", ApplyAttributeAs::Attribute)
+            ],
+            attrs.iter().collect::<Vec<_>>()
+        );
+
+        attrs
+            .entry("title")
+            .and_modify(|(value, _)| *value = format!("{value}!").into());
+        attrs
+            .entry("lang")
+            .or_insert((AttrValue::Static("en"), ApplyAttributeAs::Attribute));
+        attrs.retain(|name, _, apply_as| name != "id" && apply_as == ApplyAttributeAs::Attribute);
+
+        assert_eq!(
+            vec![
+                ("title", "c!", ApplyAttributeAs::Attribute),
+                ("lang", "en", ApplyAttributeAs::Attribute)
+            ],
+            attrs.iter().collect::<Vec<_>>()
+        );
+    }
+}
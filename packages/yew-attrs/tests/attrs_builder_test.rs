@@ -0,0 +1,70 @@
+use yew::{
+    html::Classes,
+    virtual_dom::{ListenerKind, Listeners},
+    Callback, MouseEvent,
+};
+use yew_attrs::{attrs, listeners::onclick, Attrs};
+
+#[test]
+fn builder_matches_macro() {
+    let id = "a";
+    let title: Option<&str> = None;
+    let disabled = true;
+    let hidden = false;
+    let classes = Classes::from("text-red p-2");
+
+    let attrs = Attrs::builder()
+        .attr("id", id)
+        .attr("title", title)
+        .bool_attr("disabled", disabled)
+        .bool_attr("hidden", hidden)
+        .bool_attr("checked", false)
+        .class(classes.clone())
+        .property("value", "b")
+        .build();
+
+    assert_eq!(
+        attrs! {
+            id={id}
+            title={title}
+            disabled={disabled}
+            hidden={hidden}
+            checked=false
+            class={classes}
+            ~value="b"
+        },
+        attrs
+    );
+}
+
+#[test]
+fn builder_class() {
+    let attrs = Attrs::builder()
+        .class("flex text-red")
+        .class("")
+        .class(vec!["text-red", "p-2"])
+        .build();
+
+    assert_eq!(Some("flex text-red p-2"), attrs.get("class"));
+    assert_eq!(Attrs::default(), Attrs::builder().class("").build());
+}
+
+#[test]
+fn builder_listeners() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+
+    let attrs = Attrs::builder()
+        .listener(onclick(on_click))
+        .listener(onclick(None::<Callback<MouseEvent>>))
+        .build();
+
+    let Listeners::Pending(listeners) = attrs.listeners else {
+        panic!("expected pending listeners");
+    };
+    assert_eq!(2, listeners.len());
+    assert_eq!(
+        Some(ListenerKind::onclick),
+        listeners[0].as_ref().map(|listener| listener.kind())
+    );
+    assert!(listeners[1].is_none());
+}
@@ -0,0 +1,234 @@
+use yew::virtual_dom::ApplyAttributeAs;
+use yew_attrs::{attrs, Attrs, AttrsError};
+
+fn entries(attrs: &Attrs) -> Vec<(&str, &str)> {
+    attrs.iter().map(|(name, value, _)| (name, value)).collect()
+}
+
+#[test]
+fn from_html_str() {
+    let attrs = Attrs::from_html_str(
+        "class=\"a b\" disabled data-x='1' title = unquoted aria-label=\"Tom &amp; Jerry &#x27;\" viewBox=\"0 0 24 24\" value",
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            ("class", "a b"),
+            ("disabled", "disabled"),
+            ("data-x", "1"),
+            ("title", "unquoted"),
+            ("aria-label", "Tom & Jerry '"),
+            ("viewBox", "0 0 24 24"),
+            ("value", ""),
+        ],
+        entries(&attrs)
+    );
+    assert!(attrs
+        .iter()
+        .all(|(_, _, apply_as)| apply_as == ApplyAttributeAs::Attribute));
+    assert_eq!(None, attrs.checked);
+}
+
+#[test]
+fn from_html_str_booleans() {
+    let attrs = Attrs::from_html_str("CHECKED Hidden=\"\" readonly=false").unwrap();
+
+    assert_eq!(
+        vec![
+            ("checked", "checked"),
+            ("hidden", "hidden"),
+            ("readonly", "readonly"),
+        ],
+        entries(&attrs)
+    );
+    assert_eq!(Some(true), attrs.checked);
+    assert_eq!(
+        entries(&attrs! { checked=true hidden=true readonly=true }),
+        entries(&attrs)
+    );
+}
+
+#[test]
+fn from_html_str_case_insensitive() {
+    let attrs = Attrs::from_html_str(
+        "ID=x Class=\"a\" Data-X=1 VIEWBOX=\"0 0 24 24\" preserveaspectratio=none",
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            ("id", "x"),
+            ("class", "a"),
+            ("data-x", "1"),
+            ("viewBox", "0 0 24 24"),
+            ("preserveAspectRatio", "none"),
+        ],
+        entries(&attrs)
+    );
+    assert_eq!(
+        Some("a b"),
+        attrs
+            .merge(Attrs::from_html_str("CLASS=b").unwrap())
+            .unwrap()
+            .get("class")
+    );
+}
+
+#[test]
+fn from_html_str_empty() {
+    assert_eq!(Ok(Attrs::default()), Attrs::from_html_str(""));
+    assert_eq!(Ok(Attrs::default()), Attrs::from_html_str(" \n\t"));
+}
+
+#[test]
+fn from_html_str_malformed() {
+    let error = |input: &str| Attrs::from_html_str(input).unwrap_err().to_string();
+
+    assert_eq!(
+        "invalid HTML attributes at position 8: unterminated value of attribute `class`, expected `\"`",
+        error("class=\"a")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 3: expected value of attribute `id`, found end of input",
+        error("id=")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 3: expected value of attribute `id`, found `>`",
+        error("id=>")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 4: unexpected `\"` in unquoted value of attribute `id`",
+        error("id=a\"b")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 6: expected whitespace before attribute, found `t`",
+        error("id=\"a\"title=\"b\"")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 0: expected attribute name, found `=`",
+        error("=a")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 7: duplicate attribute `id`",
+        error("id=\"a\" id=\"b\"")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 9: duplicate attribute `DISABLED`",
+        error("disabled DISABLED")
+    );
+    assert_eq!(
+        "invalid HTML attributes at position 10: duplicate attribute `class`",
+        error("CLASS=\"a\" class=\"b\"")
+    );
+    assert_eq!(
+        Err(AttrsError::Parse {
+            position: 7,
+            message: "inline event handler `onclick` is not supported, use a listener instead"
+                .into()
+        }),
+        Attrs::from_html_str("id=\"a\" onclick=\"alert(1)\"")
+    );
+}
+
+/// Assert that each boolean attribute is parsed from HTML like the `attrs!` macro sets it.
+macro_rules! assert_booleans {
+    ($($name:tt)*) => {
+        $(
+            let expected = attrs! { $name=true };
+            for input in [stringify!($name).to_string(), stringify!($name).to_ascii_uppercase()] {
+                let attrs = Attrs::from_html_str(&input).unwrap();
+
+                assert_eq!(entries(&expected), entries(&attrs), "{input}");
+                assert_eq!(expected.checked, attrs.checked, "{input}");
+            }
+        )*
+    };
+}
+
+#[test]
+fn from_html_str_booleans_match_macro() {
+    // Same as `BOOLEAN_ATTRIBUTES` in `yew-attrs` and `BOOLEAN_SET` in `yew-attrs-macro`.
+    assert_booleans!(
+        allowfullscreen async autofocus autoplay checked controls default defer disabled formnovalidate hidden ismap
+        itemscope loop multiple muted nomodule novalidate open playsinline readonly required reversed selected
+        truespeed webkitdirectory
+    );
+
+    assert_eq!(
+        vec![("title", "")],
+        entries(&Attrs::from_html_str("title").unwrap())
+    );
+}
+
+#[test]
+fn to_html_string() {
+    let on_click = yew::Callback::from(|_: yew::MouseEvent| {});
+
+    assert_eq!(
+        "id=\"a\" title=\"Tom &amp; &quot;Jerry&quot; &lt;3\" disabled=\"disabled\" class=\"flex p-2\"",
+        attrs! {
+            id="a"
+            title="Tom & \"Jerry\" <3"
+            ~value="b"
+            class="flex p-2"
+            disabled=true
+            hidden=false
+            onclick={on_click}
+        }
+        .to_html_string()
+    );
+    assert_eq!("", Attrs::default().to_html_string());
+}
+
+#[test]
+fn to_html_string_checked() {
+    assert_eq!("checked", attrs! { checked=true }.to_html_string());
+    assert_eq!(
+        "",
+        attrs! { checked=true }
+            .merge(attrs! { checked=false })
+            .unwrap()
+            .to_html_string()
+    );
+    assert_eq!(
+        "checked type=\"checkbox\"",
+        attrs! { type="checkbox" }
+            .with_checked(true)
+            .to_html_string()
+    );
+    assert_eq!(
+        "checked name=\"a\"",
+        Attrs::from_html_str("name=a checked")
+            .unwrap()
+            .to_html_string()
+    );
+}
+
+#[test]
+fn to_html_string_invalid_names() {
+    let mut attrs = attrs! { id="a" };
+    attrs.insert("x><script>alert(1)</script", "y");
+    attrs.insert("onmouseover=alert(1) x", "y");
+    attrs.insert("a b", "y");
+    attrs.insert("\"", "y");
+    attrs.insert("", "y");
+    attrs.insert("x\u{0}", "y");
+    attrs.insert("x\u{fffe}", "y");
+    attrs.insert("data-ä", "b");
+
+    assert_eq!("id=\"a\" data-ä=\"b\"", attrs.to_html_string());
+}
+
+#[test]
+fn to_html_string_roundtrip() {
+    let attrs =
+        Attrs::from_html_str("class=\"a b\" disabled data-x='&lt;1&gt;' title=\"&quot;\"").unwrap();
+
+    assert_eq!(
+        Ok(entries(&attrs)),
+        Attrs::from_html_str(&attrs.to_html_string())
+            .as_ref()
+            .map(entries)
+    );
+}
@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use yew::{
+    virtual_dom::{Listener, ListenerKind, Listeners},
+    Callback, Event, FocusEvent, MouseEvent,
+};
+use yew_attrs::{
+    attrs,
+    listeners::{on, on_passive, onblur, onclick},
+    Attrs,
+};
+
+fn kinds(attrs: &Attrs) -> Vec<ListenerKind> {
+    attrs
+        .iter_listeners()
+        .map(|listener| listener.kind())
+        .collect()
+}
+
+fn listener(listener: Option<Rc<dyn Listener>>) -> Rc<dyn Listener> {
+    listener.expect("listener should exist")
+}
+
+#[test]
+fn listeners_query() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    let attrs = attrs! { onclick={on_click.clone()} onfocus={on_focus} onclick={on_click} };
+
+    assert_eq!(
+        vec![
+            ListenerKind::onclick,
+            ListenerKind::onfocus,
+            ListenerKind::onclick
+        ],
+        kinds(&attrs)
+    );
+    assert_eq!(2, attrs.listeners_of(&ListenerKind::onclick).count());
+    assert!(attrs.has_listener(&ListenerKind::onfocus));
+    assert!(!attrs.has_listener(&ListenerKind::onblur));
+    assert!(!Attrs::default().has_listener(&ListenerKind::onclick));
+}
+
+#[test]
+fn listeners_add_remove() {
+    let mut attrs = Attrs::default();
+
+    attrs.add_listener(listener(onclick(|_: MouseEvent| {})));
+    attrs.add_listener(listener(onblur(|_: FocusEvent| {})));
+    assert_eq!(
+        vec![ListenerKind::onclick, ListenerKind::onblur],
+        kinds(&attrs)
+    );
+
+    let removed = attrs.remove_listeners(&ListenerKind::onclick);
+    assert_eq!(1, removed.len());
+    assert_eq!(vec![ListenerKind::onblur], kinds(&attrs));
+
+    attrs.remove_listeners(&ListenerKind::onblur);
+    assert!(matches!(attrs.listeners, Listeners::None));
+}
+
+#[test]
+fn listeners_replace() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    let mut attrs = attrs! { onfocus={on_focus} onclick={on_click.clone()} onclick={on_click} };
+
+    let wrapped = listener(onclick(|_: MouseEvent| {}));
+    let removed = attrs.replace_listeners(&ListenerKind::onclick, Some(wrapped.clone()));
+
+    assert_eq!(2, removed.len());
+    assert_eq!(
+        vec![ListenerKind::onfocus, ListenerKind::onclick],
+        kinds(&attrs)
+    );
+    assert!(Rc::ptr_eq(
+        &wrapped,
+        attrs.listeners_of(&ListenerKind::onclick).next().unwrap()
+    ));
+
+    let removed = attrs.replace_listeners(&ListenerKind::onblur, onblur(|_: FocusEvent| {}));
+    assert!(removed.is_empty());
+    assert_eq!(
+        vec![
+            ListenerKind::onfocus,
+            ListenerKind::onclick,
+            ListenerKind::onblur
+        ],
+        kinds(&attrs)
+    );
+}
+
+#[test]
+fn listeners_custom() {
+    let on_change = Callback::from(|_: Event| {});
+
+    let attrs = attrs! {
+        on:sl-change={on_change}
+        on:touchstart|passive={|_| {}}
+        onclick={|_| {}}
+    };
+
+    assert_eq!(
+        vec![
+            ListenerKind::other("sl-change".into()),
+            ListenerKind::other("touchstart".into()),
+            ListenerKind::onclick
+        ],
+        kinds(&attrs)
+    );
+    assert_eq!(
+        vec![false, true, false],
+        attrs
+            .iter_listeners()
+            .map(|listener| listener.passive())
+            .collect::<Vec<_>>()
+    );
+
+    let on_close = listener(on("sl-close", |_: Event| {}));
+    assert_eq!(ListenerKind::other("sl-close".into()), on_close.kind());
+    assert!(!on_close.passive());
+    assert!(listener(on_passive("sl-close", |_: Event| {})).passive());
+    assert!(on("sl-close", None::<Callback<Event>>).is_none());
+}
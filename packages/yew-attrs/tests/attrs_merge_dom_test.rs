@@ -0,0 +1,90 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Event, EventInit};
+use yew::{virtual_dom::Listeners, Callback, MouseEvent};
+use yew_attrs::{
+    attrs,
+    merge::{ListenerStrategy, MergeStrategies},
+    Attrs,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn on_click(calls: &Rc<RefCell<Vec<&'static str>>>, name: &'static str, prevent: bool) -> Attrs {
+    let calls = calls.clone();
+    let on_click = Callback::from(move |event: MouseEvent| {
+        calls.borrow_mut().push(name);
+        if prevent {
+            event.prevent_default();
+        }
+    });
+
+    attrs! { onclick={on_click} }
+}
+
+/// Merge two `onclick` listeners with a strategy and dispatch a cancelable click event to the merged listeners.
+fn click(left: Attrs, right: Attrs, strategy: ListenerStrategy) -> Event {
+    let merged = left.merge_with(right, &MergeStrategies::new().with_listeners(strategy));
+
+    let init = EventInit::new();
+    init.set_cancelable(true);
+    let event = Event::new_with_event_init_dict("click", &init).unwrap();
+
+    if let Listeners::Pending(listeners) = merged.listeners {
+        for listener in listeners.iter().flatten() {
+            listener.handle(event.clone());
+        }
+    }
+
+    event
+}
+
+#[wasm_bindgen_test]
+fn merge_listeners_compose_order() {
+    for (strategy, expected) in [
+        (ListenerStrategy::ComposeLeftFirst, vec!["left", "right"]),
+        (ListenerStrategy::ComposeRightFirst, vec!["right", "left"]),
+    ] {
+        let calls = Rc::new(RefCell::new(vec![]));
+        click(
+            on_click(&calls, "left", false),
+            on_click(&calls, "right", false),
+            strategy,
+        );
+
+        assert_eq!(expected, *calls.borrow());
+    }
+}
+
+#[wasm_bindgen_test]
+fn merge_listeners_compose_prevent_default() {
+    for (strategy, expected) in [
+        (ListenerStrategy::ComposeLeftFirst, vec!["left"]),
+        (ListenerStrategy::ComposeRightFirst, vec!["right", "left"]),
+    ] {
+        let calls = Rc::new(RefCell::new(vec![]));
+        let event = click(
+            on_click(&calls, "left", true),
+            on_click(&calls, "right", false),
+            strategy,
+        );
+
+        assert!(event.default_prevented());
+        assert_eq!(expected, *calls.borrow());
+    }
+}
+
+#[wasm_bindgen_test]
+fn merge_listeners_append_runs_all() {
+    let calls = Rc::new(RefCell::new(vec![]));
+    click(
+        on_click(&calls, "left", true),
+        on_click(&calls, "right", false),
+        ListenerStrategy::Append,
+    );
+
+    assert_eq!(vec!["left", "right"], *calls.borrow());
+}
@@ -0,0 +1,275 @@
+use indexmap::IndexMap;
+use yew::{
+    virtual_dom::{ApplyAttributeAs, Attributes, ListenerKind, Listeners},
+    AttrValue, Callback, FocusEvent, MouseEvent,
+};
+use yew_attrs::{
+    merge::{Concat, KeepLeft, ListenerStrategy, MergeStrategies, Override, TokenList},
+    Attrs,
+};
+
+fn attrs(attributes: &[(&'static str, &'static str)]) -> Attrs {
+    Attrs::new(
+        Attributes::IndexMap(
+            attributes
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        AttrValue::Static(key),
+                        (AttrValue::Static(value), ApplyAttributeAs::Attribute),
+                    )
+                })
+                .collect(),
+        ),
+        Listeners::None,
+    )
+}
+
+#[test]
+fn merge_override() {
+    let merged = attrs(&[("id", "a"), ("title", "a")])
+        .merge(attrs(&[("id", "b")]))
+        .unwrap();
+
+    assert_eq!(attrs(&[("id", "b"), ("title", "a")]), merged);
+}
+
+#[test]
+fn merge_class() {
+    let merged = attrs(&[("class", "flex text-red")])
+        .merge(attrs(&[("class", "text-red  p-2")]))
+        .unwrap();
+
+    assert_eq!(
+        Attributes::IndexMap(IndexMap::from([(
+            AttrValue::Static("class"),
+            (
+                AttrValue::from("flex text-red p-2".to_string()),
+                ApplyAttributeAs::Attribute
+            )
+        )])),
+        merged.attributes
+    );
+}
+
+#[test]
+fn merge_class_one_side() {
+    let merged = attrs(&[("class", "flex")])
+        .merge(attrs(&[("id", "a")]))
+        .unwrap();
+
+    assert_eq!(attrs(&[("class", "flex"), ("id", "a")]), merged);
+
+    let merged = attrs(&[("id", "a")])
+        .merge(attrs(&[("class", "flex")]))
+        .unwrap();
+
+    assert_eq!(attrs(&[("id", "a"), ("class", "flex")]), merged);
+}
+
+fn merge_style(a: &'static str, b: &'static str) -> String {
+    let merged = attrs(&[("style", a)])
+        .merge(attrs(&[("style", b)]))
+        .unwrap();
+
+    let Attributes::IndexMap(map) = merged.attributes else {
+        unreachable!()
+    };
+    map.get("style").unwrap().0.to_string()
+}
+
+#[test]
+fn merge_style_override() {
+    assert_eq!(
+        "COLOR: blue; width: 10px; margin: 0",
+        merge_style("color: red; width: 10px", "COLOR: blue;margin:0;")
+    );
+}
+
+#[test]
+fn merge_style_important() {
+    assert_eq!(
+        "color: red !important; width: 20px !important",
+        merge_style(
+            "color: red !important; width: 10px",
+            "color: blue; width: 20px ! IMPORTANT"
+        )
+    );
+    assert_eq!(
+        "color: blue !important",
+        merge_style("color: red !important", "color: blue !important")
+    );
+}
+
+#[test]
+fn merge_style_custom_properties() {
+    assert_eq!(
+        "--Accent: red; --accent: green; background: var(--accent, blue)",
+        merge_style(
+            "--Accent: red; --accent: blue",
+            "--accent: green; background: var(--accent, blue)"
+        )
+    );
+}
+
+#[test]
+fn merge_style_values_with_separators() {
+    assert_eq!(
+        "background: url(\"a;b.png\"); content: 'x;y'",
+        merge_style("background: url(\"a;b.png\")", "content: 'x;y'")
+    );
+}
+
+#[test]
+fn merge_style_malformed() {
+    assert_eq!(
+        "color: blue; width: 1px",
+        merge_style(
+            "color red; : 1px; width:",
+            "color: blue;;; height; width: 1px"
+        )
+    );
+}
+
+#[test]
+fn merge_with_strategies() {
+    let strategies = MergeStrategies::new()
+        .with("id", KeepLeft)
+        .with("rel", TokenList)
+        .with("aria-describedby", TokenList)
+        .with("data-path", Concat::new("/"))
+        .with("class", Override)
+        .with("title", |a: AttrValue, b: AttrValue| {
+            AttrValue::from(format!("{b} ({a})"))
+        });
+
+    let merged = attrs(&[
+        ("id", "a"),
+        ("rel", "noopener"),
+        ("aria-describedby", "hint"),
+        ("data-path", "a"),
+        ("class", "flex"),
+        ("title", "a"),
+        ("style", "color: red"),
+    ])
+    .merge_with(
+        attrs(&[
+            ("id", "b"),
+            ("rel", "noreferrer noopener"),
+            ("aria-describedby", "error hint"),
+            ("data-path", "b"),
+            ("class", "grid"),
+            ("title", "b"),
+            ("style", "width: 1px"),
+        ]),
+        &strategies,
+    );
+
+    assert_eq!(
+        attrs(&[
+            ("id", "a"),
+            ("rel", "noopener noreferrer"),
+            ("aria-describedby", "hint error"),
+            ("data-path", "a/b"),
+            ("class", "grid"),
+            ("title", "b (a)"),
+            ("style", "color: red; width: 1px"),
+        ]),
+        merged
+    );
+}
+
+#[test]
+fn merge_static_and_dynamic() {
+    let a = Attrs::new(
+        Attributes::Static(&[
+            ("id", "a", ApplyAttributeAs::Attribute),
+            ("class", "flex", ApplyAttributeAs::Attribute),
+        ]),
+        Listeners::None,
+    );
+    let b = Attrs::new(
+        Attributes::Dynamic {
+            keys: &["class", "hidden", "title"],
+            values: Box::new([
+                Some((AttrValue::Static("p-2"), ApplyAttributeAs::Attribute)),
+                None,
+                Some((AttrValue::Static("b"), ApplyAttributeAs::Property)),
+            ]),
+        },
+        Listeners::None,
+    );
+
+    assert_eq!(
+        Attributes::IndexMap(IndexMap::from([
+            (
+                AttrValue::Static("id"),
+                (AttrValue::Static("a"), ApplyAttributeAs::Attribute)
+            ),
+            (
+                AttrValue::Static("class"),
+                (
+                    AttrValue::from("flex p-2".to_string()),
+                    ApplyAttributeAs::Attribute
+                )
+            ),
+            (
+                AttrValue::Static("title"),
+                (AttrValue::Static("b"), ApplyAttributeAs::Property)
+            ),
+        ])),
+        a.merge(b).unwrap().attributes
+    );
+}
+
+fn listener_kinds(attrs: &Attrs) -> Vec<ListenerKind> {
+    match &attrs.listeners {
+        Listeners::None => vec![],
+        Listeners::Pending(listeners) => listeners
+            .iter()
+            .flatten()
+            .map(|listener| listener.kind())
+            .collect(),
+    }
+}
+
+#[test]
+fn merge_listeners_append() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    let merged = yew_attrs::attrs! { onclick={on_click.clone()} onfocus={on_focus} }
+        .merge(yew_attrs::attrs! { onclick={on_click} })
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListenerKind::onclick,
+            ListenerKind::onfocus,
+            ListenerKind::onclick
+        ],
+        listener_kinds(&merged)
+    );
+}
+
+#[test]
+fn merge_listeners_compose() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    for strategy in [
+        ListenerStrategy::ComposeLeftFirst,
+        ListenerStrategy::ComposeRightFirst,
+    ] {
+        let merged = yew_attrs::attrs! { onclick={on_click.clone()} onfocus={on_focus.clone()} }
+            .merge_with(
+                yew_attrs::attrs! { onclick={on_click.clone()} },
+                &MergeStrategies::new().with_listeners(strategy),
+            );
+
+        assert_eq!(
+            vec![ListenerKind::onclick, ListenerKind::onfocus],
+            listener_kinds(&merged)
+        );
+    }
+}
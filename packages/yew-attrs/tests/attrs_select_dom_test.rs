@@ -0,0 +1,119 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, HtmlOptionElement, HtmlSelectElement};
+use yew::{platform::time::sleep, prelude::*, Renderer};
+use yew_attrs::attrs;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, PartialEq, Properties)]
+struct SelectProps {
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub multiple: bool,
+}
+
+#[function_component]
+fn Select(props: &SelectProps) -> Html {
+    attrs! {multiple={props.multiple} value={props.value.clone()}}
+        .new_vtag(
+            "select",
+            Default::default(),
+            Default::default(),
+            html! {
+                <>
+                    <option value="apple">{"Apple"}</option>
+                    <optgroup label="Yellow">
+                        <option value="banana">{"Banana"}</option>
+                    </optgroup>
+                    <option>{"cherry"}</option>
+                </>
+            },
+        )
+        .into()
+}
+
+fn root() -> Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    root
+}
+
+async fn rendered() {
+    sleep(Duration::ZERO).await;
+}
+
+fn selected_options(select: &HtmlSelectElement) -> Vec<String> {
+    let options = select.selected_options();
+    (0..options.length())
+        .filter_map(|index| options.item(index))
+        .map(|option| option.unchecked_into::<HtmlOptionElement>().value())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+async fn new_vtag_select_dom() {
+    let root = root();
+    let mut app = Renderer::<Select>::with_root_and_props(
+        root.clone(),
+        SelectProps {
+            value: "banana".into(),
+            multiple: false,
+        },
+    )
+    .render();
+    rendered().await;
+
+    let select = root
+        .first_element_child()
+        .unwrap()
+        .unchecked_into::<HtmlSelectElement>();
+    assert_eq!("banana", select.value());
+    assert_eq!(vec!["banana"], selected_options(&select));
+
+    app.update(SelectProps {
+        value: "cherry".into(),
+        multiple: false,
+    });
+    rendered().await;
+
+    assert_eq!("cherry", select.value());
+    assert_eq!(vec!["cherry"], selected_options(&select));
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn new_vtag_select_multiple_dom() {
+    let root = root();
+    let mut app = Renderer::<Select>::with_root_and_props(
+        root.clone(),
+        SelectProps {
+            value: "apple cherry".into(),
+            multiple: true,
+        },
+    )
+    .render();
+    rendered().await;
+
+    let select = root
+        .first_element_child()
+        .unwrap()
+        .unchecked_into::<HtmlSelectElement>();
+    assert_eq!(vec!["apple", "cherry"], selected_options(&select));
+
+    app.update(SelectProps {
+        value: "banana".into(),
+        multiple: true,
+    });
+    rendered().await;
+
+    assert_eq!(vec!["banana"], selected_options(&select));
+
+    app.destroy();
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "serde")]
+
+use yew::{virtual_dom::ApplyAttributeAs, Callback, MouseEvent};
+use yew_attrs::{attrs, Attrs};
+
+#[test]
+fn serialize() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+
+    assert_eq!(
+        r#"{"id":"a","value":{"value":"b","apply_as":"property"},"disabled":"disabled"}"#,
+        serde_json::to_string(&attrs! { id="a" disabled=true ~value="b" onclick={on_click} })
+            .unwrap()
+    );
+}
+
+#[test]
+fn deserialize() {
+    let attrs: Attrs = serde_json::from_str(
+        r#"{
+            "id": "a",
+            "value": {"value": "b", "apply_as": "property"},
+            "title": {"value": "c"}
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            ("id", "a", ApplyAttributeAs::Attribute),
+            ("value", "b", ApplyAttributeAs::Property),
+            ("title", "c", ApplyAttributeAs::Attribute),
+        ],
+        attrs.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn serialize_checked() {
+    assert_eq!(
+        r#"{"type":"checkbox","checked":false}"#,
+        serde_json::to_string(&attrs! { type="checkbox" checked=false }).unwrap()
+    );
+    assert_eq!(
+        r#"{"checked":true}"#,
+        serde_json::to_string(&attrs! { checked=true }).unwrap()
+    );
+    assert_eq!(
+        r#"{"checked":"checked"}"#,
+        serde_json::to_string(&Attrs::builder().attr("checked", "checked").build()).unwrap()
+    );
+}
+
+#[test]
+fn deserialize_checked() {
+    let attrs: Attrs = serde_json::from_str(r#"{"checked": false}"#).unwrap();
+    assert_eq!(Some(false), attrs.checked);
+    assert!(!attrs.contains("checked"));
+
+    let attrs: Attrs = serde_json::from_str(r#"{"checked": true}"#).unwrap();
+    assert_eq!(Some(true), attrs.checked);
+    assert!(attrs.contains("checked"));
+
+    let attrs: Attrs = serde_json::from_str(r#"{"checked": "checked"}"#).unwrap();
+    assert_eq!(None, attrs.checked);
+    assert!(attrs.contains("checked"));
+}
+
+#[test]
+fn roundtrip() {
+    for attrs in [
+        attrs! { class="flex" aria-label="Close" ~value="b" },
+        attrs! { type="checkbox" checked=true },
+        attrs! { type="checkbox" checked=false },
+    ] {
+        let json = serde_json::to_string(&attrs).unwrap();
+        let deserialized: Attrs = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            attrs.iter().collect::<Vec<_>>(),
+            deserialized.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(attrs.checked, deserialized.checked);
+    }
+}
+
+#[test]
+fn deserialize_unsupported() {
+    let error = |json: &str| serde_json::from_str::<Attrs>(json).unwrap_err().to_string();
+
+    assert_eq!(
+        "invalid type: sequence, expected a map of attribute names to values at line 1 column 0",
+        error("[]")
+    );
+    assert!(error(r#"{"tabindex": 1}"#).starts_with(
+        "invalid type: integer `1`, expected an attribute value string or a map with `value` and `apply_as`"
+    ));
+    assert!(
+        error(r#"{"value": {"value": "a", "apply_as": "listener"}}"#)
+            .starts_with("unknown variant `listener`, expected `attribute` or `property`")
+    );
+    assert!(error(r#"{"value": {"apply_as": "property"}}"#).starts_with("missing field `value`"));
+    assert!(error(r#"{"value": {"value": "a", "onclick": "b"}}"#)
+        .starts_with("unknown field `onclick`, expected `value` or `apply_as`"));
+    assert!(error(r#"{"id": "a", "id": "b"}"#).starts_with("duplicate attribute `id`"));
+    assert!(error(r#"{"checked": true, "checked": "checked"}"#)
+        .starts_with("duplicate attribute `checked`"));
+    assert!(error(r#"{"hidden": true}"#).starts_with(
+        "boolean value `true` of attribute `hidden`, only `checked` can be a boolean"
+    ));
+    assert!(error(r#"{"onclick=\"alert(1)\" x": "a"}"#)
+        .starts_with("invalid attribute name `onclick=\"alert(1)\" x`"));
+    assert!(error(r#"{"": "a"}"#).starts_with("invalid attribute name ``"));
+}
@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use yew::{
+    html::Classes,
+    virtual_dom::{Listener, ListenerKind},
+    Callback, Event, FocusEvent, MouseEvent,
+};
+use yew_attrs::{attrs, Attrs, AttrsError};
+
+#[test]
+fn split() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    let (taken, rest) = attrs! {
+        id="a"
+        title="b"
+        disabled=true
+        checked=false
+        onclick={on_click}
+        onfocus={on_focus}
+    }
+    .split(&["disabled", "id", "onclick", "checked"]);
+
+    assert_eq!(
+        vec!["id", "disabled"],
+        taken.iter().map(|(name, _, _)| name).collect::<Vec<_>>()
+    );
+    assert_eq!(Some(false), taken.checked);
+    assert!(taken.has_listener(&ListenerKind::onclick));
+    assert!(!taken.has_listener(&ListenerKind::onfocus));
+
+    assert_eq!(
+        vec!["title"],
+        rest.iter().map(|(name, _, _)| name).collect::<Vec<_>>()
+    );
+    assert_eq!(None, rest.checked);
+    assert!(!rest.has_listener(&ListenerKind::onclick));
+    assert!(rest.has_listener(&ListenerKind::onfocus));
+}
+
+#[test]
+fn split_custom_listeners() {
+    let (taken, rest) = attrs! {
+        on:sl-change={|_| {}}
+        on:sl-close={|_| {}}
+        onclick={|_| {}}
+    }
+    .split(&["on:sl-change", "sl-close"]);
+
+    assert!(taken.has_listener(&ListenerKind::other("sl-change".into())));
+    assert!(!taken.has_listener(&ListenerKind::other("sl-close".into())));
+    assert!(rest.has_listener(&ListenerKind::other("sl-close".into())));
+    assert!(rest.has_listener(&ListenerKind::onclick));
+
+    let mut attrs = attrs! { on:sl-change={|_| {}} };
+    let on_change = attrs
+        .take::<Option<Callback<Event>>>("on:sl-change")
+        .unwrap();
+    assert!(on_change.is_some());
+    assert!(attrs.iter_listeners().next().is_none());
+}
+
+#[test]
+fn take_attributes() {
+    let mut attrs = attrs! {
+        disabled=true
+        tabindex="2"
+        aria-label="Close"
+        class="flex p-2"
+        data-x="x"
+    };
+
+    assert_eq!(Ok(true), attrs.take::<bool>("disabled"));
+    assert_eq!(Ok(false), attrs.take::<bool>("hidden"));
+    assert_eq!(Ok(2), attrs.take::<i32>("tabindex"));
+    assert_eq!(Ok(None), attrs.take::<Option<i32>>("tabindex"));
+    assert_eq!(
+        Ok(Some("Close".to_string())),
+        attrs.take::<Option<String>>("aria-label")
+    );
+    assert_eq!(
+        Ok(Classes::from("flex p-2")),
+        attrs.take::<Classes>("class")
+    );
+    assert_eq!(
+        Err(AttrsError::Missing("title".into())),
+        attrs.take::<String>("title")
+    );
+    assert!(matches!(
+        attrs.take::<u8>("data-x"),
+        Err(AttrsError::Invalid { name, value, .. }) if name == "data-x" && value == "x"
+    ));
+    assert!(matches!(
+        attrs.take::<Option<u8>>("data-x"),
+        Err(AttrsError::Invalid { name, .. }) if name == "data-x"
+    ));
+    assert_eq!(Some("x"), attrs.get("data-x"));
+    assert_eq!(Ok("x".to_string()), attrs.take::<String>("data-x"));
+    assert!(attrs.is_empty());
+}
+
+#[test]
+fn take_checked() {
+    assert_eq!(Ok(false), attrs! { checked=false }.take::<bool>("checked"));
+    assert_eq!(Ok(true), attrs! { checked=true }.take::<bool>("checked"));
+}
+
+#[test]
+fn take_listeners() {
+    let on_click = Callback::from(|_: MouseEvent| {});
+    let on_focus = Callback::from(|_: FocusEvent| {});
+
+    let mut attrs = attrs! { onclick={on_click.clone()} onfocus={on_focus} onclick={on_click} };
+
+    let listeners = attrs.take::<Vec<Rc<dyn Listener>>>("onclick").unwrap();
+    assert_eq!(2, listeners.len());
+    assert!(!attrs.has_listener(&ListenerKind::onclick));
+
+    assert!(attrs
+        .take::<Option<Callback<Event>>>("onfocus")
+        .unwrap()
+        .is_some());
+    assert!(attrs
+        .take::<Option<Callback<Event>>>("onfocus")
+        .unwrap()
+        .is_none());
+    assert_eq!(Attrs::default(), attrs);
+}
@@ -0,0 +1,244 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use yew::{
+    prelude::*,
+    virtual_dom::{ApplyAttributeAs, Attributes},
+    ServerRenderer,
+};
+use yew_attrs::{attrs, Attrs};
+
+#[derive(PartialEq, Properties)]
+struct ElementProps {
+    pub tag: AttrValue,
+    #[prop_or_default]
+    pub attrs: Attrs,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component]
+fn Element(props: &ElementProps) -> Html {
+    props
+        .attrs
+        .clone()
+        .new_vtag(
+            &props.tag,
+            Default::default(),
+            Default::default(),
+            props.children.clone(),
+        )
+        .into()
+}
+
+async fn render<C: BaseComponent<Properties = ()>>() -> String {
+    ServerRenderer::<C>::new().hydratable(false).render().await
+}
+
+#[function_component]
+fn SelectApp() -> Html {
+    html! {
+        <Element tag="select" attrs={attrs! {name="fruit" value="banana"}}>
+            <option value="apple" selected=true>{"Apple"}</option>
+            <optgroup label="Yellow">
+                <option value="banana">{"Banana"}</option>
+            </optgroup>
+            <option>{" banana "}</option>
+        </Element>
+    }
+}
+
+#[tokio::test]
+async fn new_vtag_select() {
+    assert_eq!(
+        "<select name=\"fruit\">\
+        <option value=\"apple\">Apple</option>\
+        <optgroup label=\"Yellow\"><option value=\"banana\" selected=\"selected\">Banana</option></optgroup>\
+        <option> banana </option>\
+        </select>",
+        render::<SelectApp>().await
+    );
+}
+
+#[function_component]
+fn SelectMultipleApp() -> Html {
+    html! {
+        <Element tag="select" attrs={attrs! {multiple=true value="apple cherry"}}>
+            <option value="apple">{"Apple"}</option>
+            <option value="banana">{"Banana"}</option>
+            <option>{"cherry"}</option>
+        </Element>
+    }
+}
+
+#[tokio::test]
+async fn new_vtag_select_multiple() {
+    assert_eq!(
+        "<select multiple=\"multiple\">\
+        <option value=\"apple\" selected=\"selected\">Apple</option>\
+        <option value=\"banana\">Banana</option>\
+        <option selected=\"selected\">cherry</option>\
+        </select>",
+        render::<SelectMultipleApp>().await
+    );
+}
+
+#[function_component]
+fn CheckboxApp() -> Html {
+    let checked = false;
+
+    html! {
+        <>
+            <Element tag="input" attrs={attrs! {type="checkbox"}} />
+            <Element tag="input" attrs={attrs! {type="checkbox" checked=true}} />
+            <Element tag="input" attrs={attrs! {type="checkbox" checked={checked}}} />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn new_vtag_checked() {
+    assert_eq!(
+        "<input type=\"checkbox\">\
+        <input checked type=\"checkbox\">\
+        <input type=\"checkbox\">",
+        render::<CheckboxApp>().await
+    );
+}
+
+#[test]
+fn new_vtag_checked_state() {
+    let vtag = |attrs: Attrs| {
+        attrs.new_vtag(
+            "input",
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+    };
+
+    assert_eq!(None, vtag(attrs! {type="checkbox"}).checked());
+    assert_eq!(Some(true), vtag(attrs! {checked=true}).checked());
+    assert_eq!(Some(false), vtag(attrs! {checked=false}).checked());
+    assert_eq!(
+        Some(false),
+        vtag(attrs! {checked=true}.merge(attrs! {checked=false}).unwrap()).checked()
+    );
+    assert_eq!(
+        Some(true),
+        vtag(
+            attrs! {checked=false}
+                .merge(Attrs::builder().attr("checked", "checked").build())
+                .unwrap()
+        )
+        .checked()
+    );
+    assert_eq!(
+        Some(false),
+        vtag(
+            attrs! {checked=false}
+                .merge(attrs! {type="checkbox"})
+                .unwrap()
+        )
+        .checked()
+    );
+}
+
+#[test]
+fn new_vtag_case_insensitive() {
+    for tag in ["input", "INPUT", "Input"] {
+        let vtag = attrs! {value="a" checked=true}.new_vtag(
+            tag,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        assert_eq!("input", vtag.tag());
+        assert_eq!(Some(&AttrValue::Static("a")), vtag.value());
+        assert_eq!(Some(true), vtag.checked());
+    }
+
+    let vtag = attrs! {value="a"}.new_vtag(
+        "TextArea",
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    assert_eq!("textarea", vtag.tag());
+    assert_eq!(Some(&AttrValue::Static("a")), vtag.value());
+}
+
+#[test]
+fn new_vtag_static_attributes() {
+    for tag in ["input", "textarea"] {
+        let vtag = Attrs::new(
+            Attributes::Static(&[("value", "a", ApplyAttributeAs::Attribute)]),
+            Default::default(),
+        )
+        .new_vtag(
+            tag,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        assert_eq!(tag, vtag.tag());
+        assert_eq!(Some(&AttrValue::Static("a")), vtag.value());
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct IconProps {
+    #[prop_or_default]
+    pub attrs: Attrs,
+}
+
+#[function_component]
+fn Icon(props: &IconProps) -> Html {
+    let path = attrs! {d="M0 0h24v24H0z"}.new_vtag(
+        "path",
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+
+    props
+        .attrs
+        .clone()
+        .new_vtag("SVG", Default::default(), Default::default(), path.into())
+        .into()
+}
+
+#[function_component]
+fn IconApp() -> Html {
+    html! {
+        <Icon attrs={attrs! {viewBox="0 0 24 24" class="icon"}} />
+    }
+}
+
+#[tokio::test]
+async fn new_vtag_svg() {
+    assert_eq!(
+        "<svg viewBox=\"0 0 24 24\" class=\"icon\"><path d=\"M0 0h24v24H0z\"></path></svg>",
+        render::<IconApp>().await
+    );
+
+    let vtag = Attrs::default().new_vtag(
+        "foreignObject",
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    assert_eq!("foreignObject", vtag.tag());
+}
+
+#[test]
+fn new_vtag_mathml() {
+    let vtag = Attrs::default().new_vtag(
+        "MATH",
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    assert_eq!("math", vtag.tag());
+}